@@ -0,0 +1,176 @@
+use crate::{point::Point2D, rect::Rect2D, size::Size2D, vec::Vec2};
+use serde::Deserialize;
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Aabb2 {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl Aabb2 {
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    pub fn from_center_extents(center: Vec2, extents: Vec2) -> Self {
+        Self::new(center - extents, center + extents)
+    }
+
+    pub fn from_points(points: &[Vec2]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| {
+            aabb.include_point(*point)
+        }))
+    }
+
+    pub fn min(&self) -> Vec2 {
+        self.min
+    }
+
+    pub fn max(&self) -> Vec2 {
+        self.max
+    }
+
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// Half of the size along each axis.
+    pub fn extents(&self) -> Vec2 {
+        self.size() * 0.5
+    }
+
+    pub fn area(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    pub fn perimeter(&self) -> f32 {
+        let size = self.size();
+        2.0 * (size.x + size.y)
+    }
+
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self::new(self.min.min(&rhs.min), self.max.max(&rhs.max))
+    }
+
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let min = self.min.max(&rhs.min);
+        let max = self.max.min(&rhs.max);
+        if min.x > max.x || min.y > max.y {
+            None
+        } else {
+            Some(Self::new(min, max))
+        }
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        self.intersection(rhs).is_some()
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    pub fn contains_aabb(&self, rhs: &Self) -> bool {
+        self.contains(rhs.min) && self.contains(rhs.max)
+    }
+
+    /// Grows the box by `amount` on every side.
+    pub fn expand(&self, amount: f32) -> Self {
+        let amount = Vec2::new(amount, amount);
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    pub fn include_point(&self, point: Vec2) -> Self {
+        Self::new(self.min.min(&point), self.max.max(&point))
+    }
+
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        point.max(&self.min).min(&self.max)
+    }
+
+    /// Corners in counter-clockwise order, starting at `min`.
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.min,
+            Vec2::new(self.max.x, self.min.y),
+            self.max,
+            Vec2::new(self.min.x, self.max.y),
+        ]
+    }
+}
+
+impl From<Rect2D<f32, f32>> for Aabb2 {
+    fn from(rect: Rect2D<f32, f32>) -> Self {
+        let min = Vec2::new(rect.offset().x(), rect.offset().y());
+        Self::new(min, min + Vec2::new(rect.width(), rect.height()))
+    }
+}
+
+impl From<Aabb2> for Rect2D<f32, f32> {
+    fn from(aabb: Aabb2) -> Self {
+        let size = aabb.size();
+        Self::from_offset_and_size(
+            Point2D::new(aabb.min.x, aabb.min.y),
+            Size2D::new(size.x, size.y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_and_intersection() {
+        let a = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+        let b = Aabb2::new(Vec2::new(1.0, -1.0), Vec2::new(4.0, 1.5));
+        let union = a.union(&b);
+        assert_eq!(union.min(), Vec2::new(0.0, -1.0));
+        assert_eq!(union.max(), Vec2::new(4.0, 2.0));
+
+        let overlap = a.intersection(&b).unwrap();
+        assert_eq!(overlap.min(), Vec2::new(1.0, 0.0));
+        assert_eq!(overlap.max(), Vec2::new(2.0, 1.5));
+        assert_eq!(overlap.area(), 1.5);
+
+        let far = Aabb2::new(Vec2::new(3.0, 3.0), Vec2::new(4.0, 4.0));
+        assert_eq!(a.intersection(&far), None);
+        assert!(!a.intersects(&far));
+    }
+
+    #[test]
+    fn closest_point() {
+        let aabb = Aabb2::new(Vec2::new(-1.0, -2.0), Vec2::new(1.0, 2.0));
+        assert_eq!(aabb.closest_point(Vec2::new(0.5, 0.5)), Vec2::new(0.5, 0.5));
+        assert_eq!(aabb.closest_point(Vec2::new(3.0, 0.0)), Vec2::new(1.0, 0.0));
+        assert_eq!(
+            aabb.closest_point(Vec2::new(-5.0, -5.0)),
+            Vec2::new(-1.0, -2.0)
+        );
+    }
+
+    #[test]
+    fn from_points() {
+        assert_eq!(Aabb2::from_points(&[]), None);
+        let aabb = Aabb2::from_points(&[
+            Vec2::new(1.0, 5.0),
+            Vec2::new(-2.0, 3.0),
+            Vec2::new(0.0, -1.0),
+        ])
+        .unwrap();
+        assert_eq!(aabb.min(), Vec2::new(-2.0, -1.0));
+        assert_eq!(aabb.max(), Vec2::new(1.0, 5.0));
+        assert!(aabb.contains(Vec2::new(0.0, 0.0)));
+        assert!(!aabb.contains(Vec2::new(2.0, 0.0)));
+    }
+}
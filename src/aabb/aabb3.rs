@@ -0,0 +1,209 @@
+use crate::{mat::Mat4, vec::Vec3};
+use serde::Deserialize;
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Aabb3 {
+    min: Vec3,
+    max: Vec3,
+}
+
+impl Aabb3 {
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    pub fn from_center_extents(center: Vec3, extents: Vec3) -> Self {
+        Self::new(center - extents, center + extents)
+    }
+
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| {
+            aabb.include_point(*point)
+        }))
+    }
+
+    pub fn min(&self) -> Vec3 {
+        self.min
+    }
+
+    pub fn max(&self) -> Vec3 {
+        self.max
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Half of the size along each axis.
+    pub fn extents(&self) -> Vec3 {
+        self.size() * 0.5
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        let size = self.size();
+        2.0 * (size.x() * size.y() + size.y() * size.z() + size.z() * size.x())
+    }
+
+    pub fn volume(&self) -> f32 {
+        let size = self.size();
+        size.x() * size.y() * size.z()
+    }
+
+    pub fn union(&self, rhs: &Self) -> Self {
+        Self::new(self.min.min(&rhs.min), self.max.max(&rhs.max))
+    }
+
+    pub fn intersection(&self, rhs: &Self) -> Option<Self> {
+        let min = self.min.max(&rhs.min);
+        let max = self.max.min(&rhs.max);
+        if min.x() > max.x() || min.y() > max.y() || min.z() > max.z() {
+            None
+        } else {
+            Some(Self::new(min, max))
+        }
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        self.intersection(rhs).is_some()
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        point.x() >= self.min.x()
+            && point.x() <= self.max.x()
+            && point.y() >= self.min.y()
+            && point.y() <= self.max.y()
+            && point.z() >= self.min.z()
+            && point.z() <= self.max.z()
+    }
+
+    pub fn contains_aabb(&self, rhs: &Self) -> bool {
+        self.contains(rhs.min) && self.contains(rhs.max)
+    }
+
+    /// Grows the box by `amount` on every side.
+    pub fn expand(&self, amount: f32) -> Self {
+        let amount = Vec3::new(amount, amount, amount);
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    pub fn include_point(&self, point: Vec3) -> Self {
+        Self::new(self.min.min(&point), self.max.max(&point))
+    }
+
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.max(&self.min).min(&self.max)
+    }
+
+    /// Corners in binary order: bit 0 selects max x, bit 1 max y, bit 2 max z.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            Vec3::new(min.x(), min.y(), min.z()),
+            Vec3::new(max.x(), min.y(), min.z()),
+            Vec3::new(min.x(), max.y(), min.z()),
+            Vec3::new(max.x(), max.y(), min.z()),
+            Vec3::new(min.x(), min.y(), max.z()),
+            Vec3::new(max.x(), min.y(), max.z()),
+            Vec3::new(min.x(), max.y(), max.z()),
+            Vec3::new(max.x(), max.y(), max.z()),
+        ]
+    }
+
+    /// Tight bounds of the transformed box, using Arvo's method.
+    /// Assumes `mat` is affine.
+    pub fn transform(&self, mat: &Mat4) -> Self {
+        let mut min = [0.0; 3];
+        let mut max = [0.0; 3];
+        for i in 0..3 {
+            let row = mat.row(i);
+            min[i] = row.w();
+            max[i] = row.w();
+            for j in 0..3 {
+                let a = row.idx(j) * self.min.idx(j);
+                let b = row.idx(j) * self.max.idx(j);
+                min[i] += a.min(b);
+                max[i] += a.max(b);
+            }
+        }
+        Self::new(min.into(), max.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn unit() -> Aabb3 {
+        Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0))
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a = unit();
+        let b = Aabb3::new(Vec3::new(0.0, 0.5, -3.0), Vec3::new(2.0, 4.0, 0.0));
+        let union = a.union(&b);
+        assert_eq!(union.min(), Vec3::new(-1.0, -1.0, -3.0));
+        assert_eq!(union.max(), Vec3::new(2.0, 4.0, 1.0));
+        assert!(union.contains_aabb(&a) && union.contains_aabb(&b));
+
+        let overlap = a.intersection(&b).unwrap();
+        assert_eq!(overlap.min(), Vec3::new(0.0, 0.5, -1.0));
+        assert_eq!(overlap.max(), Vec3::new(1.0, 1.0, 0.0));
+
+        let far = Aabb3::new(Vec3::new(1.5, 0.0, 0.0), Vec3::new(2.0, 1.0, 1.0));
+        assert_eq!(a.intersection(&far), None);
+        assert!(!a.intersects(&far));
+    }
+
+    #[test]
+    fn closest_point() {
+        let aabb = unit();
+        assert_vec(
+            aabb.closest_point(Vec3::new(0.2, -0.3, 0.4)),
+            Vec3::new(0.2, -0.3, 0.4),
+        );
+        assert_vec(
+            aabb.closest_point(Vec3::new(5.0, 0.5, 0.0)),
+            Vec3::new(1.0, 0.5, 0.0),
+        );
+        assert_vec(
+            aabb.closest_point(Vec3::new(-3.0, 2.0, 9.0)),
+            Vec3::new(-1.0, 1.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn transform_matches_corners() {
+        let aabb = Aabb3::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 1.0, 2.5));
+        let mat = Mat4::translate(Vec3::new(4.0, -2.0, 1.0))
+            * Mat4::rotate(0.7, Vec3::new(1.0, 2.0, 0.5).normalized())
+            * Mat4::scale(Vec3::new(2.0, 0.5, -1.0));
+
+        let corners: Vec<Vec3> = aabb
+            .corners()
+            .iter()
+            .map(|corner| mat.transform_point(*corner))
+            .collect();
+        let expected = Aabb3::from_points(&corners).unwrap();
+        let transformed = aabb.transform(&mat);
+        assert_vec(transformed.min(), expected.min());
+        assert_vec(transformed.max(), expected.max());
+
+        let moved = aabb.transform(&Mat4::translate(Vec3::new(1.0, 2.0, 3.0)));
+        assert_vec(moved.min(), Vec3::new(0.0, 2.0, 5.0));
+        assert_vec(moved.max(), Vec3::new(4.0, 3.0, 5.5));
+    }
+}
@@ -0,0 +1,4 @@
+mod aabb2;
+mod aabb3;
+
+pub use self::{aabb2::Aabb2, aabb3::Aabb3};
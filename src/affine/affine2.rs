@@ -0,0 +1,311 @@
+use crate::{aabb::Aabb2, mat::Mat3, rect::Rect2D, vec::Vec2};
+use serde::Deserialize;
+
+/// 2x2 linear part, stored as its columns, and translation of a 2D affine
+/// transform. Points transform as `x_axis * x + y_axis * y + translation`.
+#[repr(C)]
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq)]
+pub struct Affine2 {
+    x_axis: Vec2,
+    y_axis: Vec2,
+    translation: Vec2,
+}
+
+impl Affine2 {
+    pub fn new(x_axis: Vec2, y_axis: Vec2, translation: Vec2) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), Vec2::zero())
+    }
+
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self::new(Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), translation)
+    }
+
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(Vec2::new(cos, sin), Vec2::new(-sin, cos), Vec2::zero())
+    }
+
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self::new(
+            Vec2::new(scale.x, 0.0),
+            Vec2::new(0.0, scale.y),
+            Vec2::zero(),
+        )
+    }
+
+    /// Shears by angles in radians: `skew_x` tilts vertical lines towards
+    /// +X and `skew_y` tilts horizontal lines towards +Y.
+    pub fn from_skew(skew_x: f32, skew_y: f32) -> Self {
+        Self::new(
+            Vec2::new(1.0, skew_y.tan()),
+            Vec2::new(skew_x.tan(), 1.0),
+            Vec2::zero(),
+        )
+    }
+
+    /// This transform followed by a translation, so calls read in the order
+    /// they apply: `Affine2::identity().scale(s).rotate(a).translate(t)`.
+    pub fn translate(self, translation: Vec2) -> Self {
+        Self::from_translation(translation) * self
+    }
+
+    /// This transform followed by a counter-clockwise rotation about the origin.
+    pub fn rotate(self, angle: f32) -> Self {
+        Self::from_angle(angle) * self
+    }
+
+    /// This transform followed by a scale about the origin.
+    pub fn scale(self, scale: Vec2) -> Self {
+        Self::from_scale(scale) * self
+    }
+
+    /// This transform followed by a skew; see `from_skew`.
+    pub fn skew(self, skew_x: f32, skew_y: f32) -> Self {
+        Self::from_skew(skew_x, skew_y) * self
+    }
+
+    pub fn x_axis(&self) -> Vec2 {
+        self.x_axis
+    }
+
+    pub fn y_axis(&self) -> Vec2 {
+        self.y_axis
+    }
+
+    pub fn translation(&self) -> Vec2 {
+        self.translation
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.cross(&self.y_axis)
+    }
+
+    /// Takes a `Vec2` or a `Point2D<f32>`.
+    pub fn transform_point<P: Into<Vec2> + From<Vec2>>(&self, point: P) -> P {
+        (self.transform_vector(point.into()) + self.translation).into()
+    }
+
+    /// Ignores the translation.
+    pub fn transform_vector(&self, vec: Vec2) -> Vec2 {
+        self.x_axis * vec.x + self.y_axis * vec.y
+    }
+
+    /// Smallest box containing the transformed box.
+    pub fn transform_aabb(&self, aabb: &Aabb2) -> Aabb2 {
+        let corners = aabb.corners().map(|corner| self.transform_point(corner));
+        Aabb2::from_points(&corners).unwrap_or_default()
+    }
+
+    /// Smallest rectangle containing the transformed rectangle.
+    pub fn transform_rect(&self, rect: &Rect2D<f32, f32>) -> Rect2D<f32, f32> {
+        self.transform_aabb(&Aabb2::from(*rect)).into()
+    }
+
+    /// Returns `None` if the transform is singular.
+    pub fn try_inverse(self) -> Option<Self> {
+        (self.determinant() != 0.0).then(|| self.inverse())
+    }
+
+    pub fn inverse(self) -> Self {
+        let inv_det = 1.0 / self.determinant();
+        let x_axis = Vec2::new(self.y_axis.y, -self.x_axis.y) * inv_det;
+        let y_axis = Vec2::new(-self.y_axis.x, self.x_axis.x) * inv_det;
+        let inverse = Self::new(x_axis, y_axis, Vec2::zero());
+        Self::new(
+            x_axis,
+            y_axis,
+            inverse.transform_vector(self.translation).negate(),
+        )
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Applies `rhs` first, then `self`, as with matrices.
+impl std::ops::Mul<Affine2> for Affine2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        Self::new(
+            self.transform_vector(rhs.x_axis),
+            self.transform_vector(rhs.y_axis),
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+/// Homogeneous 2D matrix.
+impl From<Affine2> for Mat3 {
+    #[rustfmt::skip]
+    fn from(affine: Affine2) -> Self {
+        Mat3::from_data(
+            affine.x_axis.x, affine.y_axis.x, affine.translation.x,
+            affine.x_axis.y, affine.y_axis.y, affine.translation.y,
+            0.0, 0.0, 1.0,
+        )
+    }
+}
+
+/// From a homogeneous 2D matrix, dropping the bottom row.
+impl From<Mat3> for Affine2 {
+    fn from(mat: Mat3) -> Self {
+        Self::new(
+            Vec2::new(mat.idx(0, 0), mat.idx(1, 0)),
+            Vec2::new(mat.idx(0, 1), mat.idx(1, 1)),
+            Vec2::new(mat.idx(0, 2), mat.idx(1, 2)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point::Point2D, size::Size2D, vec::Vec3};
+
+    fn assert_vec(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected).magnitude() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn transform() -> Affine2 {
+        Affine2::new(
+            Vec2::new(2.0, 1.0),
+            Vec2::new(-0.5, 1.5),
+            Vec2::new(3.0, -4.0),
+        )
+    }
+
+    fn points() -> [Vec2; 3] {
+        [Vec2::zero(), Vec2::new(1.0, 2.0), Vec2::new(-4.0, 0.5)]
+    }
+
+    #[test]
+    fn composition_applies_right_first() {
+        let a = transform();
+        let b = Affine2::from_translation(Vec2::new(0.0, 5.0)) * Affine2::from_angle(1.2);
+        for point in points() {
+            assert_vec(
+                (a * b).transform_point(point),
+                a.transform_point(b.transform_point(point)),
+            );
+        }
+        assert_eq!(a * Affine2::identity(), a);
+        assert_eq!(Affine2::default(), Affine2::identity());
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let affine = transform();
+        let inverse = affine.try_inverse().unwrap();
+        for point in points() {
+            assert_vec(
+                inverse.transform_point(affine.transform_point(point)),
+                point,
+            );
+            assert_vec((affine * inverse).transform_point(point), point);
+        }
+        assert!((affine.determinant() * inverse.determinant() - 1.0).abs() < 1e-5);
+
+        let singular = Affine2::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0), Vec2::zero());
+        assert_eq!(singular.try_inverse(), None);
+    }
+
+    #[test]
+    fn mat3_round_trip() {
+        let affine = transform();
+        let mat = Mat3::from(affine);
+        assert_eq!(Affine2::from(mat), affine);
+        for point in points() {
+            let homogeneous = mat * Vec3::new(point.x, point.y, 1.0);
+            assert_vec(
+                Vec2::new(homogeneous.x(), homogeneous.y()),
+                affine.transform_point(point),
+            );
+            assert_eq!(homogeneous.z(), 1.0);
+        }
+
+        let inverse = Affine2::from(mat.inverse());
+        for point in points() {
+            assert_vec(
+                inverse.transform_point(point),
+                affine.inverse().transform_point(point),
+            );
+        }
+    }
+
+    #[test]
+    fn builders_apply_in_call_order() {
+        use std::f32::consts::FRAC_PI_2;
+
+        let affine = Affine2::identity()
+            .scale(Vec2::new(2.0, 1.0))
+            .rotate(FRAC_PI_2)
+            .translate(Vec2::new(10.0, 0.0));
+        // (1, 0) scales to (2, 0), rotates to (0, 2), then moves to (10, 2).
+        assert_vec(
+            affine.transform_point(Vec2::new(1.0, 0.0)),
+            Vec2::new(10.0, 2.0),
+        );
+        assert_vec(
+            affine.transform_vector(Vec2::new(1.0, 0.0)),
+            Vec2::new(0.0, 2.0),
+        );
+
+        let reversed = Affine2::identity()
+            .translate(Vec2::new(10.0, 0.0))
+            .rotate(FRAC_PI_2)
+            .scale(Vec2::new(2.0, 1.0));
+        assert_vec(
+            reversed.transform_point(Vec2::new(1.0, 0.0)),
+            Vec2::new(0.0, 11.0),
+        );
+
+        let skewed = Affine2::identity().skew(std::f32::consts::FRAC_PI_4, 0.0);
+        assert_vec(
+            skewed.transform_point(Vec2::new(0.0, 1.0)),
+            Vec2::new(1.0, 1.0),
+        );
+        assert_vec(
+            skewed.transform_point(Vec2::new(1.0, 0.0)),
+            Vec2::new(1.0, 0.0),
+        );
+
+        let point = affine.transform_point(Point2D::new(1.0, 0.0));
+        assert!((point.x() - 10.0).abs() < 1e-5 && (point.y() - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn transform_rect_bounds_rotated_corners() {
+        let rect = Rect2D::from_offset_and_size(Point2D::new(1.0, 1.0), Size2D::new(2.0, 1.0));
+        let quarter = Affine2::from_angle(std::f32::consts::FRAC_PI_2).transform_rect(&rect);
+        assert_vec(quarter.offset().into(), Vec2::new(-2.0, 1.0));
+        assert!((quarter.width() - 1.0).abs() < 1e-5);
+        assert!((quarter.height() - 2.0).abs() < 1e-5);
+
+        let square = Rect2D::from_width_height(1.0, 1.0);
+        let eighth = Affine2::from_angle(std::f32::consts::FRAC_PI_4).transform_rect(&square);
+        let half_diagonal = std::f32::consts::FRAC_1_SQRT_2;
+        assert_vec(eighth.offset().into(), Vec2::new(-half_diagonal, 0.0));
+        assert!((eighth.width() - 2.0 * half_diagonal).abs() < 1e-5);
+        assert!((eighth.height() - 2.0 * half_diagonal).abs() < 1e-5);
+
+        let moved = Affine2::from_translation(Vec2::new(5.0, -1.0)).transform_rect(&rect);
+        assert_vec(moved.offset().into(), Vec2::new(6.0, 0.0));
+        assert_eq!(moved.size(), rect.size());
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    mat::{Mat3, Mat4},
+    quat::Quat,
+    vec::{Vec3, Vec4},
+};
+use serde::Deserialize;
+
+/// Linear part and translation of an affine transform: the top three rows
+/// of a `Mat4` whose last row is `[0, 0, 0, 1]`. Points transform as
+/// `matrix * point + translation`.
+#[repr(C)]
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq)]
+pub struct Affine3 {
+    matrix: Mat3,
+    translation: Vec3,
+}
+
+impl Affine3 {
+    pub fn new(matrix: Mat3, translation: Vec3) -> Self {
+        Self {
+            matrix,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Mat3::identity(), Vec3::zero())
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::new(Mat3::identity(), translation)
+    }
+
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::new(Mat3::rotation_from_quat(rotation), Vec3::zero())
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::new(
+            Mat3::from_cols(
+                Vec3::new(scale.x(), 0.0, 0.0),
+                Vec3::new(0.0, scale.y(), 0.0),
+                Vec3::new(0.0, 0.0, scale.z()),
+            ),
+            Vec3::zero(),
+        )
+    }
+
+    /// Scales, then rotates, then translates.
+    pub fn from_trs(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        let rotation = Mat3::rotation_from_quat(rotation);
+        Self::new(
+            Mat3::from_cols(
+                rotation.col(0) * scale.x(),
+                rotation.col(1) * scale.y(),
+                rotation.col(2) * scale.z(),
+            ),
+            translation,
+        )
+    }
+
+    pub fn matrix(&self) -> Mat3 {
+        self.matrix
+    }
+
+    pub fn translation(&self) -> Vec3 {
+        self.translation
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.matrix.determinant()
+    }
+
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.matrix * point + self.translation
+    }
+
+    /// Ignores the translation.
+    pub fn transform_vector(&self, vec: Vec3) -> Vec3 {
+        self.matrix * vec
+    }
+
+    /// Returns `None` if the transform is singular.
+    pub fn try_inverse(self) -> Option<Self> {
+        (self.determinant() != 0.0).then(|| self.inverse())
+    }
+
+    /// Only the 3x3 part is inverted, which is much cheaper than `Mat4::inverse`.
+    pub fn inverse(self) -> Self {
+        let matrix = self.matrix.inverse();
+        Self::new(matrix, (matrix * self.translation).negate())
+    }
+}
+
+impl Default for Affine3 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Applies `rhs` first, then `self`, as with matrices.
+impl std::ops::Mul<Affine3> for Affine3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Affine3) -> Self::Output {
+        Self::new(
+            self.matrix * rhs.matrix,
+            self.matrix * rhs.translation + self.translation,
+        )
+    }
+}
+
+impl From<Affine3> for Mat4 {
+    fn from(affine: Affine3) -> Self {
+        let axis = |idx: usize| {
+            let col = affine.matrix.col(idx);
+            [col.x(), col.y(), col.z(), 0.0]
+        };
+        let cols: [[f32; 4]; 4] = [
+            axis(0),
+            axis(1),
+            axis(2),
+            Vec4::from(affine.translation).into(),
+        ];
+        cols.into()
+    }
+}
+
+/// Drops the bottom row, so projective matrices lose their projection.
+impl From<Mat4> for Affine3 {
+    fn from(mat: Mat4) -> Self {
+        Self::new(Mat3::from(mat), mat.col(3).xyz())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn transform() -> Affine3 {
+        Affine3::from_trs(
+            Vec3::new(1.0, -2.0, 3.0),
+            Quat::from_axis_angle(Vec3::new(1.0, 2.0, 2.0).normalized(), 0.8),
+            Vec3::new(2.0, 0.5, -1.5),
+        )
+    }
+
+    fn points() -> [Vec3; 3] {
+        [
+            Vec3::zero(),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, 2.0),
+        ]
+    }
+
+    #[test]
+    fn composition_applies_right_first() {
+        let a = transform();
+        let b = Affine3::from_translation(Vec3::new(0.0, 5.0, 0.0))
+            * Affine3::from_quat(Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 1.2));
+        for point in points() {
+            assert_vec(
+                (a * b).transform_point(point),
+                a.transform_point(b.transform_point(point)),
+            );
+        }
+        assert_vec(
+            (a * b).transform_vector(Vec3::new(1.0, 1.0, 0.0)),
+            a.transform_vector(b.transform_vector(Vec3::new(1.0, 1.0, 0.0))),
+        );
+        assert_eq!(a * Affine3::identity(), a);
+        assert_eq!(Affine3::default(), Affine3::identity());
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let affine = transform();
+        let inverse = affine.try_inverse().unwrap();
+        for point in points() {
+            assert_vec(
+                inverse.transform_point(affine.transform_point(point)),
+                point,
+            );
+            assert_vec((affine * inverse).transform_point(point), point);
+        }
+        assert!((affine.determinant() * inverse.determinant() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn singular_has_no_inverse() {
+        let flat = Affine3::from_scale(Vec3::new(1.0, 0.0, 2.0));
+        assert_eq!(flat.try_inverse(), None);
+        assert_eq!(
+            Affine3::new(Mat3::default(), Vec3::zero()).try_inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn mat4_round_trip() {
+        let affine = transform();
+        let mat = Mat4::from(affine);
+        assert_eq!(mat.row(3), Vec4::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Affine3::from(mat), affine);
+
+        let expected = Mat4::translate(Vec3::new(1.0, -2.0, 3.0))
+            * Mat4::rotation_from_quat(Quat::from_axis_angle(
+                Vec3::new(1.0, 2.0, 2.0).normalized(),
+                0.8,
+            ))
+            * Mat4::scale(Vec3::new(2.0, 0.5, -1.5));
+        for point in points() {
+            assert_vec(mat.transform_point(point), affine.transform_point(point));
+            assert_vec(
+                expected.transform_point(point),
+                affine.transform_point(point),
+            );
+        }
+
+        let inverse = Affine3::from(Mat4::from(affine).inverse());
+        for point in points() {
+            assert_vec(
+                inverse.transform_point(point),
+                affine.inverse().transform_point(point),
+            );
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod affine2;
+mod affine3;
+
+pub use self::{affine2::Affine2, affine3::Affine3};
@@ -0,0 +1,68 @@
+mod track;
+
+pub use self::track::{Cursor, Keyframe, Track, TransformCursor, TransformTracks};
+
+use crate::{lerp::Lerp, quat::Quat, vec::Vec3};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Holds each key's value until the next key.
+    Step,
+    #[default]
+    Linear,
+    /// Hermite spline through the keys using their in and out tangents, as
+    /// in glTF's `CUBICSPLINE`.
+    CubicSpline,
+}
+
+/// How sample times outside a track's keys are mapped back onto it.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
+pub enum WrapMode {
+    #[default]
+    Clamp,
+    Loop,
+    PingPong,
+}
+
+impl WrapMode {
+    pub fn apply(self, time: f32, start: f32, end: f32) -> f32 {
+        let duration = end - start;
+        if duration <= 0.0 {
+            return start;
+        }
+
+        match self {
+            WrapMode::Clamp => time.clamp(start, end),
+            WrapMode::Loop => start + (time - start).rem_euclid(duration),
+            WrapMode::PingPong => {
+                let phase = (time - start).rem_euclid(2.0 * duration);
+                if phase > duration {
+                    end - (phase - duration)
+                } else {
+                    start + phase
+                }
+            }
+        }
+    }
+}
+
+/// Values a `Track` can hold.
+pub trait Animatable:
+    Lerp + Copy + std::ops::Add<Output = Self> + std::ops::Mul<f32, Output = Self>
+{
+    /// Applied to cubic spline results, which leave the value's valid set.
+    fn finish_cubic(self) -> Self {
+        self
+    }
+}
+
+impl Animatable for f32 {}
+
+impl Animatable for Vec3 {}
+
+impl Animatable for Quat {
+    fn finish_cubic(self) -> Self {
+        self.normalized()
+    }
+}
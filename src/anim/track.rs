@@ -0,0 +1,370 @@
+use super::{Animatable, Interpolation, WrapMode};
+use crate::{mat::Mat4, quat::Quat, vec::Vec3};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Keyframe<T> {
+    time: f32,
+    value: T,
+    in_tangent: T,
+    out_tangent: T,
+}
+
+impl<T: Animatable> Keyframe<T> {
+    /// Key with zero tangents.
+    pub fn new(time: f32, value: T) -> Self {
+        Self::with_tangents(time, value * 0.0, value, value * 0.0)
+    }
+
+    /// Tangents are derivatives per second, as stored in glTF.
+    pub fn with_tangents(time: f32, in_tangent: T, value: T, out_tangent: T) -> Self {
+        Self {
+            time,
+            value,
+            in_tangent,
+            out_tangent,
+        }
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    pub fn in_tangent(&self) -> T {
+        self.in_tangent
+    }
+
+    pub fn out_tangent(&self) -> T {
+        self.out_tangent
+    }
+}
+
+/// Last key visited by a track, so that sampling a track with steadily
+/// advancing times finds the next key without a search.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    key: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<T> {
+    keys: Vec<Keyframe<T>>,
+    interpolation: Interpolation,
+    wrap: WrapMode,
+}
+
+impl<T: Animatable> Track<T> {
+    /// Sorts the keys by time. Returns `None` if there are none.
+    pub fn new(
+        mut keys: Vec<Keyframe<T>>,
+        interpolation: Interpolation,
+        wrap: WrapMode,
+    ) -> Option<Self> {
+        if keys.is_empty() {
+            return None;
+        }
+        keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        Some(Self {
+            keys,
+            interpolation,
+            wrap,
+        })
+    }
+
+    pub fn keys(&self) -> &[Keyframe<T>] {
+        &self.keys
+    }
+
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    pub fn wrap(&self) -> WrapMode {
+        self.wrap
+    }
+
+    pub fn set_wrap(&mut self, wrap: WrapMode) {
+        self.wrap = wrap;
+    }
+
+    pub fn start(&self) -> f32 {
+        self.keys[0].time
+    }
+
+    pub fn end(&self) -> f32 {
+        self.keys[self.keys.len() - 1].time
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.end() - self.start()
+    }
+
+    pub fn sample(&self, time: f32) -> T {
+        self.sample_with_cursor(time, &mut Cursor::default())
+    }
+
+    /// Like `sample`, but starts looking for the key from `cursor` and leaves
+    /// it at the key found.
+    pub fn sample_with_cursor(&self, time: f32, cursor: &mut Cursor) -> T {
+        let time = self.wrap.apply(time, self.start(), self.end());
+        if self.keys.len() == 1 || time <= self.start() {
+            return self.keys[0].value;
+        }
+        if time >= self.end() {
+            return self.keys[self.keys.len() - 1].value;
+        }
+
+        let idx = self.find(time, cursor.key);
+        cursor.key = idx;
+
+        let (a, b) = (&self.keys[idx], &self.keys[idx + 1]);
+        let dt = b.time - a.time;
+        let t = if dt > 0.0 { (time - a.time) / dt } else { 0.0 };
+
+        match self.interpolation {
+            Interpolation::Step => a.value,
+            Interpolation::Linear => a.value.lerp(&b.value, t),
+            Interpolation::CubicSpline => {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                (a.value * (2.0 * t3 - 3.0 * t2 + 1.0)
+                    + a.out_tangent * ((t3 - 2.0 * t2 + t) * dt)
+                    + b.value * (-2.0 * t3 + 3.0 * t2)
+                    + b.in_tangent * ((t3 - t2) * dt))
+                    .finish_cubic()
+            }
+        }
+    }
+
+    /// Index of the key starting the span containing `time`, which must lie
+    /// strictly between the first and last keys.
+    fn find(&self, time: f32, hint: usize) -> usize {
+        let spans = self.keys.len() - 1;
+        let hint = hint.min(spans - 1);
+        for idx in hint..(hint + 2).min(spans) {
+            if self.keys[idx].time <= time && time < self.keys[idx + 1].time {
+                return idx;
+            }
+        }
+
+        self.keys.partition_point(|key| key.time <= time) - 1
+    }
+}
+
+/// One [`Cursor`] per channel of a [`TransformTracks`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct TransformCursor {
+    translation: Cursor,
+    rotation: Cursor,
+    scale: Cursor,
+}
+
+/// Translation, rotation and scale channels of one node. Missing channels
+/// hold the identity.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransformTracks {
+    pub translation: Option<Track<Vec3>>,
+    pub rotation: Option<Track<Quat>>,
+    pub scale: Option<Track<Vec3>>,
+}
+
+impl TransformTracks {
+    /// Earliest and latest key times over all channels.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        [
+            self.translation
+                .as_ref()
+                .map(|track| (track.start(), track.end())),
+            self.rotation
+                .as_ref()
+                .map(|track| (track.start(), track.end())),
+            self.scale
+                .as_ref()
+                .map(|track| (track.start(), track.end())),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+    }
+
+    pub fn sample(&self, time: f32) -> (Vec3, Quat, Vec3) {
+        self.sample_with_cursor(time, &mut TransformCursor::default())
+    }
+
+    /// Like `sample`, but keeps each channel's place in `cursor`.
+    pub fn sample_with_cursor(
+        &self,
+        time: f32,
+        cursor: &mut TransformCursor,
+    ) -> (Vec3, Quat, Vec3) {
+        (
+            self.translation.as_ref().map_or(Vec3::zero(), |track| {
+                track.sample_with_cursor(time, &mut cursor.translation)
+            }),
+            self.rotation.as_ref().map_or(Quat::identity(), |track| {
+                track.sample_with_cursor(time, &mut cursor.rotation)
+            }),
+            self.scale
+                .as_ref()
+                .map_or(Vec3::new(1.0, 1.0, 1.0), |track| {
+                    track.sample_with_cursor(time, &mut cursor.scale)
+                }),
+        )
+    }
+
+    pub fn sample_mat4(&self, time: f32) -> Mat4 {
+        let (translation, rotation, scale) = self.sample(time);
+        Mat4::from_trs(translation, rotation, scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(interpolation: Interpolation, wrap: WrapMode) -> Track<f32> {
+        Track::new(
+            vec![
+                Keyframe::new(2.0, 4.0),
+                Keyframe::new(0.0, 0.0),
+                Keyframe::new(1.0, 2.0),
+            ],
+            interpolation,
+            wrap,
+        )
+        .unwrap()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn step_holds_each_key() {
+        let track = track(Interpolation::Step, WrapMode::Clamp);
+        assert_eq!(track.sample(-1.0), 0.0);
+        assert_eq!(track.sample(0.0), 0.0);
+        assert_eq!(track.sample(0.99), 0.0);
+        assert_eq!(track.sample(1.0), 2.0);
+        assert_eq!(track.sample(1.5), 2.0);
+        assert_eq!(track.sample(2.0), 4.0);
+        assert_eq!(track.sample(3.0), 4.0);
+    }
+
+    #[test]
+    fn linear_between_keys() {
+        let track = track(Interpolation::Linear, WrapMode::Clamp);
+        assert_eq!(track.start(), 0.0);
+        assert_eq!(track.end(), 2.0);
+        assert_close(track.sample(0.25), 0.5);
+        assert_close(track.sample(1.5), 3.0);
+        assert_close(track.sample(5.0), 4.0);
+    }
+
+    #[test]
+    fn wrap_modes() {
+        let looping = track(Interpolation::Linear, WrapMode::Loop);
+        assert_close(looping.sample(2.5), 1.0);
+        assert_close(looping.sample(-0.5), 3.0);
+
+        let ping_pong = track(Interpolation::Linear, WrapMode::PingPong);
+        assert_close(ping_pong.sample(2.5), 3.0);
+        assert_close(ping_pong.sample(4.5), 1.0);
+    }
+
+    #[test]
+    fn cubic_spline_uses_scaled_tangents() {
+        // Tangents are per second, so they scale by the 2 second span.
+        let track = Track::new(
+            vec![
+                Keyframe::with_tangents(0.0, 0.0, 0.0, 2.0),
+                Keyframe::with_tangents(2.0, 0.0, 1.0, 0.0),
+            ],
+            Interpolation::CubicSpline,
+            WrapMode::Clamp,
+        )
+        .unwrap();
+        assert_close(track.sample(0.0), 0.0);
+        assert_close(track.sample(0.5), 0.71875);
+        assert_close(track.sample(1.0), 1.0);
+        assert_close(track.sample(2.0), 1.0);
+    }
+
+    #[test]
+    fn cursor_matches_search() {
+        let keys: Vec<Keyframe<f32>> = (0..20)
+            .map(|i| Keyframe::new(i as f32 * 0.5, (i * i) as f32))
+            .collect();
+        let track = Track::new(keys, Interpolation::Linear, WrapMode::Loop).unwrap();
+
+        let mut cursor = Cursor::default();
+        for step in 0..200 {
+            // Forwards through two loops, with a jump back each loop.
+            let time = step as f32 * 0.0973;
+            assert_eq!(
+                track.sample_with_cursor(time, &mut cursor),
+                track.sample(time)
+            );
+        }
+        for step in (0..50).rev() {
+            let time = step as f32 * 0.19;
+            assert_eq!(
+                track.sample_with_cursor(time, &mut cursor),
+                track.sample(time)
+            );
+        }
+    }
+
+    #[test]
+    fn transform_tracks() {
+        let quarter_turn =
+            Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), std::f32::consts::FRAC_PI_2);
+        let tracks = TransformTracks {
+            translation: Track::new(
+                vec![
+                    Keyframe::new(0.0, Vec3::zero()),
+                    Keyframe::new(2.0, Vec3::new(2.0, 4.0, 0.0)),
+                ],
+                Interpolation::Linear,
+                WrapMode::Clamp,
+            ),
+            rotation: Track::new(
+                vec![
+                    Keyframe::new(1.0, Quat::identity()),
+                    Keyframe::new(3.0, quarter_turn),
+                ],
+                Interpolation::Step,
+                WrapMode::Clamp,
+            ),
+            scale: None,
+        };
+        assert_eq!(tracks.range(), Some((0.0, 3.0)));
+
+        let (translation, rotation, scale) = tracks.sample(0.5);
+        assert!((translation - Vec3::new(0.5, 1.0, 0.0)).magnitude() < 1e-5);
+        assert_eq!(rotation, Quat::identity());
+        assert_eq!(scale, Vec3::new(1.0, 1.0, 1.0));
+
+        let (translation, rotation, _) = tracks.sample(3.0);
+        assert_eq!(translation, Vec3::new(2.0, 4.0, 0.0));
+        assert_eq!(rotation, quarter_turn);
+
+        let point = tracks
+            .sample_mat4(3.0)
+            .transform_point(Vec3::new(1.0, 0.0, 0.0));
+        assert!((point - Vec3::new(2.0, 5.0, 0.0)).magnitude() < 1e-5);
+
+        let mut cursor = TransformCursor::default();
+        for step in 0..40 {
+            let time = step as f32 * 0.1;
+            assert_eq!(
+                tracks.sample_with_cursor(time, &mut cursor),
+                tracks.sample(time)
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{aabb::Aabb3, segment::Segment3, sphere::Sphere, vec::Vec3};
+use serde::Deserialize;
+
+/// All points within `radius` of the segment from `start` to `end`.
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Capsule {
+    segment: Segment3,
+    radius: f32,
+}
+
+impl Capsule {
+    pub fn new(start: Vec3, end: Vec3, radius: f32) -> Self {
+        Self::from_segment(Segment3::new(start, end), radius)
+    }
+
+    pub fn from_segment(segment: Segment3, radius: f32) -> Self {
+        Self { segment, radius }
+    }
+
+    pub fn segment(&self) -> Segment3 {
+        self.segment
+    }
+
+    pub fn start(&self) -> Vec3 {
+        self.segment.start()
+    }
+
+    pub fn end(&self) -> Vec3 {
+        self.segment.end()
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn volume(&self) -> f32 {
+        let radius_squared = self.radius * self.radius;
+        std::f32::consts::PI * radius_squared * (self.segment.length() + 4.0 / 3.0 * self.radius)
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        self.segment.distance_squared_to_point(point) <= self.radius * self.radius
+    }
+
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let center = self.segment.closest_point(point);
+        let offset = point - center;
+        let distance = offset.magnitude();
+        if distance <= self.radius {
+            point
+        } else {
+            center + offset * (self.radius / distance)
+        }
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let radii = self.radius + rhs.radius;
+        self.segment.distance_squared(&rhs.segment) <= radii * radii
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let radii = self.radius + sphere.radius();
+        self.segment.distance_squared_to_point(sphere.center()) <= radii * radii
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb3 {
+        let radius = Vec3::new(self.radius, self.radius, self.radius);
+        Aabb3::new(
+            self.start().min(&self.end()) - radius,
+            self.start().max(&self.end()) + radius,
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use super::Color;
+use serde::Deserialize;
+
+/// Porter-Duff compositing operators, with the color being drawn as the
+/// source and the existing color as the destination.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CompositeOp {
+    Clear,
+    Source,
+    Destination,
+    #[default]
+    SourceOver,
+    DestinationOver,
+    SourceIn,
+    DestinationIn,
+    SourceOut,
+    DestinationOut,
+    SourceAtop,
+    DestinationAtop,
+    Xor,
+    /// Sum of both, clamped. Not one of the original twelve.
+    Plus,
+}
+
+impl CompositeOp {
+    /// Fractions of the source and destination kept, given their alphas.
+    fn factors(self, source_alpha: f32, destination_alpha: f32) -> (f32, f32) {
+        match self {
+            CompositeOp::Clear => (0.0, 0.0),
+            CompositeOp::Source => (1.0, 0.0),
+            CompositeOp::Destination => (0.0, 1.0),
+            CompositeOp::SourceOver => (1.0, 1.0 - source_alpha),
+            CompositeOp::DestinationOver => (1.0 - destination_alpha, 1.0),
+            CompositeOp::SourceIn => (destination_alpha, 0.0),
+            CompositeOp::DestinationIn => (0.0, source_alpha),
+            CompositeOp::SourceOut => (1.0 - destination_alpha, 0.0),
+            CompositeOp::DestinationOut => (0.0, 1.0 - source_alpha),
+            CompositeOp::SourceAtop => (destination_alpha, 1.0 - source_alpha),
+            CompositeOp::DestinationAtop => (1.0 - destination_alpha, source_alpha),
+            CompositeOp::Xor => (1.0 - destination_alpha, 1.0 - source_alpha),
+            CompositeOp::Plus => (1.0, 1.0),
+        }
+    }
+}
+
+impl Color {
+    /// Composites `self` over (or per `op`, with) `destination`. Both colors
+    /// and the result use straight alpha.
+    pub fn composite(&self, destination: &Self, op: CompositeOp) -> Self {
+        self.premultiplied()
+            .composite_premultiplied(&destination.premultiplied(), op)
+            .unpremultiplied()
+    }
+
+    /// As `composite`, for colors that are already premultiplied.
+    pub fn composite_premultiplied(&self, destination: &Self, op: CompositeOp) -> Self {
+        let (source_factor, destination_factor) = op.factors(self.a(), destination.a());
+        let result = *self * source_factor + *destination * destination_factor;
+        match op {
+            CompositeOp::Plus => result.clamped(),
+            _ => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn porter_duff_operators() {
+        let source = Color::new(1.0, 0.0, 0.0, 0.5);
+        let destination = Color::new(0.0, 0.0, 1.0, 0.25);
+
+        let expected = [
+            (CompositeOp::Clear, Color::TRANSPARENT),
+            (CompositeOp::Source, source),
+            (CompositeOp::Destination, destination),
+            (CompositeOp::SourceOver, Color::new(0.8, 0.0, 0.2, 0.625)),
+            (
+                CompositeOp::DestinationOver,
+                Color::new(0.6, 0.0, 0.4, 0.625),
+            ),
+            (CompositeOp::SourceIn, Color::new(1.0, 0.0, 0.0, 0.125)),
+            (CompositeOp::DestinationIn, Color::new(0.0, 0.0, 1.0, 0.125)),
+            (CompositeOp::SourceOut, Color::new(1.0, 0.0, 0.0, 0.375)),
+            (
+                CompositeOp::DestinationOut,
+                Color::new(0.0, 0.0, 1.0, 0.125),
+            ),
+            (CompositeOp::SourceAtop, Color::new(0.5, 0.0, 0.5, 0.25)),
+            (
+                CompositeOp::DestinationAtop,
+                Color::new(0.75, 0.0, 0.25, 0.5),
+            ),
+            (CompositeOp::Xor, Color::new(0.75, 0.0, 0.25, 0.5)),
+            (
+                CompositeOp::Plus,
+                Color::new(2.0 / 3.0, 0.0, 1.0 / 3.0, 0.75),
+            ),
+        ];
+        for (op, expected) in expected {
+            let actual = source.composite(&destination, op);
+            let (a, e): ([f32; 4], [f32; 4]) = (actual.into(), expected.into());
+            assert!(
+                a.iter().zip(e).all(|(a, e)| (a - e).abs() < 1e-6),
+                "{op:?}: {actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn opaque_source_over_replaces() {
+        let source = Color::new(0.3, 0.6, 0.9, 1.0);
+        let destination = Color::new(1.0, 1.0, 0.0, 0.7);
+        assert_eq!(
+            source.composite(&destination, CompositeOp::default()),
+            source
+        );
+
+        // Plus clamps the premultiplied sum.
+        let plus = Color::WHITE.composite_premultiplied(&Color::WHITE, CompositeOp::Plus);
+        assert_eq!(plus, Color::WHITE);
+    }
+}
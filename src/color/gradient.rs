@@ -0,0 +1,57 @@
+use super::{Color, Oklab};
+use crate::lerp::{inverse_lerp, Lerp};
+
+/// Color stops at positions along `[0, 1]`, blended in Oklab so the
+/// midpoints don't dip in brightness the way linear or sRGB blends do.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<(f32, Oklab)>,
+}
+
+impl Gradient {
+    /// Sorts the stops by position.
+    pub fn new(stops: &[(f32, Color)]) -> Self {
+        let mut stops: Vec<(f32, Oklab)> = stops
+            .iter()
+            .map(|(position, color)| (*position, Oklab::from(*color)))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops }
+    }
+
+    /// Evenly spaced stops.
+    pub fn even(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops: Vec<(f32, Color)> = colors
+            .iter()
+            .enumerate()
+            .map(|(idx, color)| (idx as f32 / last, *color))
+            .collect();
+        Self::new(&stops)
+    }
+
+    /// Positions outside the stops take the nearest stop's color. An empty
+    /// gradient is transparent.
+    pub fn sample(&self, position: f32) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::TRANSPARENT;
+        };
+        if position <= first.0 {
+            return first.1.into();
+        }
+        if position >= last.0 {
+            return last.1.into();
+        }
+
+        let upper = self.stops.partition_point(|stop| stop.0 <= position);
+        let (a, b) = (self.stops[upper - 1], self.stops[upper]);
+        a.1.lerp(&b.1, inverse_lerp(a.0, b.0, position)).into()
+    }
+}
+
+impl Color {
+    /// Perceptual blend through Oklab.
+    pub fn mix(&self, rhs: &Self, t: f32) -> Self {
+        Oklab::from(*self).lerp(&Oklab::from(*rhs), t).into()
+    }
+}
@@ -0,0 +1,174 @@
+use super::Color;
+use serde::Deserialize;
+
+/// Hue in degrees `[0, 360)`, saturation and value in `[0, 1]`. Like CSS,
+/// the model is defined over sRGB-encoded channels, not linear ones.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub alpha: f32,
+}
+
+/// Hue in degrees `[0, 360)`, saturation and lightness in `[0, 1]`, over
+/// sRGB-encoded channels.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub alpha: f32,
+}
+
+impl Hsv {
+    pub fn new(h: f32, s: f32, v: f32, alpha: f32) -> Self {
+        Self { h, s, v, alpha }
+    }
+}
+
+impl Hsl {
+    pub fn new(h: f32, s: f32, l: f32, alpha: f32) -> Self {
+        Self { h, s, l, alpha }
+    }
+}
+
+/// Hue of sRGB channels, along with their max and min.
+fn hue(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (h, max, min)
+}
+
+/// sRGB channels from hue, chroma and the amount added to every channel.
+fn from_hue(h: f32, chroma: f32, offset: f32) -> [f32; 3] {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    [r + offset, g + offset, b + offset]
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = color.to_srgb();
+        let (h, max, min) = hue(r, g, b);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Self::new(h, s, max, alpha)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        let chroma = hsv.v * hsv.s;
+        let [r, g, b] = from_hue(hsv.h, chroma, hsv.v - chroma);
+        Color::from_srgb(r, g, b, hsv.alpha)
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let [r, g, b, alpha] = color.to_srgb();
+        let (h, max, min) = hue(r, g, b);
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Self::new(h, s, l, alpha)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        let [r, g, b] = from_hue(hsl.h, chroma, hsl.l - chroma / 2.0);
+        Color::from_srgb(r, g, b, hsl.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    fn hsv(color: Color) -> [f32; 4] {
+        let hsv = Hsv::from(color);
+        [hsv.h, hsv.s, hsv.v, hsv.alpha]
+    }
+
+    fn hsl(color: Color) -> [f32; 4] {
+        let hsl = Hsl::from(color);
+        [hsl.h, hsl.s, hsl.l, hsl.alpha]
+    }
+
+    #[test]
+    fn known_values() {
+        let orange = Color::from_srgb(1.0, 0.5, 0.0, 1.0);
+        assert_close(hsv(orange), [30.0, 1.0, 1.0, 1.0]);
+        assert_close(hsl(orange), [30.0, 1.0, 0.5, 1.0]);
+
+        let steel = Color::from_srgb(0.25, 0.5, 0.75, 0.5);
+        assert_close(hsv(steel), [210.0, 2.0 / 3.0, 0.75, 0.5]);
+        assert_close(hsl(steel), [210.0, 0.5, 0.5, 0.5]);
+
+        let magenta = Color::from_srgb(1.0, 0.0, 1.0, 1.0);
+        assert_close(hsv(magenta), [300.0, 1.0, 1.0, 1.0]);
+
+        let grey = Color::from_srgb(0.4, 0.4, 0.4, 1.0);
+        assert_close(hsv(grey), [0.0, 0.0, 0.4, 1.0]);
+        assert_close(hsl(grey), [0.0, 0.0, 0.4, 1.0]);
+        assert_close(hsl(Color::BLACK), [0.0, 0.0, 0.0, 1.0]);
+
+        assert_close(
+            Color::from(Hsv::new(120.0, 0.5, 0.8, 1.0)).to_srgb(),
+            [0.4, 0.8, 0.4, 1.0],
+        );
+        assert_close(
+            Color::from(Hsl::new(240.0, 1.0, 0.25, 1.0)).to_srgb(),
+            [0.0, 0.0, 0.5, 1.0],
+        );
+        // Hue wraps around.
+        assert_close(
+            Color::from(Hsv::new(-60.0, 1.0, 1.0, 1.0)).to_srgb(),
+            magenta.to_srgb(),
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for r in 0..=4 {
+            for g in 0..=4 {
+                for b in 0..=4 {
+                    let color =
+                        Color::from_srgb(r as f32 / 4.0, g as f32 / 4.0, b as f32 / 4.0, 0.7);
+                    assert_close(Color::from(Hsv::from(color)).to_srgb(), color.to_srgb());
+                    assert_close(Color::from(Hsl::from(color)).to_srgb(), color.to_srgb());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use super::Color;
+use crate::{mat::Mat3, vec::Vec3};
+use serde::Deserialize;
+
+/// D65 reference white.
+const WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+const DELTA: f32 = 6.0 / 29.0;
+
+/// CIE 1931 XYZ relative to the D65 white point, with `y` in `[0, 1]`.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub alpha: f32,
+}
+
+/// CIELAB under D65, with `l` in `[0, 100]`.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+/// Cylindrical CIELAB, with hue in degrees `[0, 360)`.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Lch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+impl Xyz {
+    pub fn new(x: f32, y: f32, z: f32, alpha: f32) -> Self {
+        Self { x, y, z, alpha }
+    }
+}
+
+impl Lab {
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// CIEDE2000 color difference (Sharma, Wu and Dalal's formulation), where
+    /// a difference of about 1 is just noticeable.
+    pub fn delta_e_2000(&self, rhs: &Self) -> f32 {
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (rhs.l, rhs.a, rhs.b);
+
+        let c_mean = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+        let c_mean_7 = c_mean.powi(7);
+        let g = 0.5 * (1.0 - (c_mean_7 / (c_mean_7 + 25f32.powi(7))).sqrt());
+
+        let a1 = a1 * (1.0 + g);
+        let a2 = a2 * (1.0 + g);
+        let c1 = (a1 * a1 + b1 * b1).sqrt();
+        let c2 = (a2 * a2 + b2 * b2).sqrt();
+        let hue = |a: f32, b: f32| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let h1 = hue(a1, b1);
+        let h2 = hue(a2, b2);
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 <= h1 {
+            h2 - h1 + 360.0
+        } else {
+            h2 - h1 - 360.0
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let c_mean_7 = c_mean.powi(7);
+        let r_c = 2.0 * (c_mean_7 / (c_mean_7 + 25f32.powi(7))).sqrt();
+        let l_offset = (l_mean - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let l_term = delta_l / s_l;
+        let c_term = delta_c / s_c;
+        let h_term = delta_h / s_h;
+        (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt()
+    }
+}
+
+impl Lch {
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+#[rustfmt::skip]
+fn linear_srgb_to_xyz() -> Mat3 {
+    Mat3::from_data(
+        0.412_456_4, 0.357_576_1, 0.180_437_5,
+        0.212_672_9, 0.715_152_2, 0.072_175,
+        0.019_333_9, 0.119_192, 0.950_304_1,
+    )
+}
+
+#[rustfmt::skip]
+fn xyz_to_linear_srgb() -> Mat3 {
+    Mat3::from_data(
+        3.240_454_2, -1.537_138_5, -0.498_531_4,
+        -0.969_266, 1.876_010_8, 0.041_556,
+        0.055_643_4, -0.204_025_9, 1.057_225_2,
+    )
+}
+
+fn lab_f(t: f32) -> f32 {
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inverse(t: f32) -> f32 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+impl From<Color> for Xyz {
+    fn from(color: Color) -> Self {
+        let xyz = linear_srgb_to_xyz() * Vec3::from(color);
+        Self::new(xyz.x(), xyz.y(), xyz.z(), color.a())
+    }
+}
+
+impl From<Xyz> for Color {
+    fn from(xyz: Xyz) -> Self {
+        let rgb = xyz_to_linear_srgb() * Vec3::new(xyz.x, xyz.y, xyz.z);
+        Color::new(rgb.r(), rgb.g(), rgb.b(), xyz.alpha)
+    }
+}
+
+impl From<Xyz> for Lab {
+    fn from(xyz: Xyz) -> Self {
+        let fx = lab_f(xyz.x / WHITE[0]);
+        let fy = lab_f(xyz.y / WHITE[1]);
+        let fz = lab_f(xyz.z / WHITE[2]);
+        Self::new(
+            116.0 * fy - 16.0,
+            500.0 * (fx - fy),
+            200.0 * (fy - fz),
+            xyz.alpha,
+        )
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(lab: Lab) -> Self {
+        let fy = (lab.l + 16.0) / 116.0;
+        let fx = fy + lab.a / 500.0;
+        let fz = fy - lab.b / 200.0;
+        Self::new(
+            WHITE[0] * lab_f_inverse(fx),
+            WHITE[1] * lab_f_inverse(fy),
+            WHITE[2] * lab_f_inverse(fz),
+            lab.alpha,
+        )
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        Xyz::from(lab).into()
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        let (c, h) = to_polar(lab.a, lab.b);
+        Self::new(lab.l, c, h, lab.alpha)
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let (a, b) = from_polar(lch.c, lch.h);
+        Self::new(lch.l, a, b, lch.alpha)
+    }
+}
+
+impl From<Color> for Lch {
+    fn from(color: Color) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Self {
+        Lab::from(lch).into()
+    }
+}
+
+/// Chroma and hue in degrees of an `a`/`b` pair.
+pub(super) fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    (
+        (a * a + b * b).sqrt(),
+        b.atan2(a).to_degrees().rem_euclid(360.0),
+    )
+}
+
+pub(super) fn from_polar(c: f32, h: f32) -> (f32, f32) {
+    let h = h.to_radians();
+    (c * h.cos(), c * h.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_lab(actual: Lab, expected: [f32; 3], tolerance: f32) {
+        let actual_values = [actual.l, actual.a, actual.b];
+        for (a, e) in actual_values.iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn known_srgb_values() {
+        assert_lab(Lab::from(Color::WHITE), [100.0, 0.0, 0.0], 0.01);
+        assert_lab(Lab::from(Color::BLACK), [0.0, 0.0, 0.0], 1e-4);
+        assert_lab(
+            Lab::from(Color::rgb(1.0, 0.0, 0.0)),
+            [53.2408, 80.0925, 67.2032],
+            0.01,
+        );
+        assert_lab(
+            Lab::from(Color::rgb(0.0, 1.0, 0.0)),
+            [87.7347, -86.1827, 83.1793],
+            0.01,
+        );
+        assert_lab(
+            Lab::from(Color::rgb(0.0, 0.0, 1.0)),
+            [32.2970, 79.1875, -107.8602],
+            0.01,
+        );
+
+        let xyz = Xyz::from(Color::WHITE);
+        assert!((xyz.x - WHITE[0]).abs() < 1e-4);
+        assert!((xyz.y - WHITE[1]).abs() < 1e-4);
+        assert!((xyz.z - WHITE[2]).abs() < 1e-4);
+    }
+
+    #[test]
+    fn round_trips() {
+        for color in [
+            Color::new(0.2, 0.5, 0.9, 1.0),
+            Color::new(0.9, 0.1, 0.3, 0.5),
+            Color::new(0.001, 0.002, 0.001, 1.0),
+            Color::WHITE,
+        ] {
+            let via_lab = Color::from(Lab::from(color));
+            let via_lch = Color::from(Lch::from(color));
+            for back in [via_lab, via_lch] {
+                let (expected, actual): ([f32; 4], [f32; 4]) = (color.into(), back.into());
+                for (e, a) in expected.iter().zip(actual) {
+                    assert!((e - a).abs() < 1e-4, "{color:?} != {back:?}");
+                }
+            }
+        }
+
+        let lch = Lch::from(Lab::new(50.0, 0.0, -20.0, 1.0));
+        assert!((lch.c - 20.0).abs() < 1e-5);
+        assert!((lch.h - 270.0).abs() < 1e-4);
+    }
+
+    /// Pairs from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference
+    /// Formula: Implementation Notes, Supplementary Test Data, and
+    /// Mathematical Observations", table 1.
+    #[test]
+    fn delta_e_2000_reference_pairs() {
+        #[rustfmt::skip]
+        let pairs: [([f32; 3], [f32; 3], f32); 34] = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+            ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+            ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0009], 7.1792),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0010], 7.1792),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0011], 7.2195),
+            ([50.0, 2.4900, -0.0010], [50.0, -2.4900, 0.0012], 7.2195),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0009, -2.4900], 4.8045),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0010, -2.4900], 4.8045),
+            ([50.0, -0.0010, 2.4900], [50.0, 0.0011, -2.4900], 4.7461),
+            ([50.0, 2.5000, 0.0], [50.0, 0.0, -2.5000], 4.3065),
+            ([50.0, 2.5000, 0.0], [73.0, 25.0, -18.0], 27.1492),
+            ([50.0, 2.5000, 0.0], [61.0, -5.0, 29.0], 22.8977),
+            ([50.0, 2.5000, 0.0], [56.0, -27.0, -3.0], 31.9030),
+            ([50.0, 2.5000, 0.0], [58.0, 24.0, 15.0], 19.4535),
+            ([50.0, 2.5000, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+            ([50.0, 2.5000, 0.0], [50.0, 3.2972, 0.0], 1.0000),
+            ([50.0, 2.5000, 0.0], [50.0, 1.8634, 0.5757], 1.0000),
+            ([50.0, 2.5000, 0.0], [50.0, 3.2592, 0.3350], 1.0000),
+            ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+            ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+            ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+            ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+            ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+            ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+            ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+            ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+            ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+            ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+
+        for ([l1, a1, b1], [l2, a2, b2], expected) in pairs {
+            let (lhs, rhs) = (Lab::new(l1, a1, b1, 1.0), Lab::new(l2, a2, b2, 1.0));
+            let delta = lhs.delta_e_2000(&rhs);
+            assert!(
+                (delta - expected).abs() < 1e-3,
+                "{lhs:?} to {rhs:?}: {delta} != {expected}"
+            );
+            assert!((rhs.delta_e_2000(&lhs) - expected).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+mod blend;
+mod gradient;
+mod hsv;
+mod lab;
+mod oklab;
+mod rgba;
+
+pub use self::{
+    blend::CompositeOp,
+    gradient::Gradient,
+    hsv::{Hsl, Hsv},
+    lab::{Lab, Lch, Xyz},
+    oklab::{Oklab, Oklch},
+    rgba::{Color, ParseColorError},
+};
+
+/// sRGB electro-optical transfer function, mapping an encoded channel in
+/// `[0, 1]` to linear light.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Inverse of `srgb_to_linear`.
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    lab::{from_polar, to_polar},
+    Color,
+};
+use crate::lerp::Lerp;
+use serde::Deserialize;
+
+/// Björn Ottosson's perceptual color space, with `l` in `[0, 1]`. Straight
+/// lines through it give even-looking gradients.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+/// Cylindrical Oklab, with hue in degrees `[0, 360)`.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    /// Euclidean distance, Oklab's own color difference.
+    pub fn distance(&self, rhs: &Self) -> f32 {
+        let (dl, da, db) = (self.l - rhs.l, self.a - rhs.a, self.b - rhs.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl Oklch {
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl Lerp for Oklab {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Self::new(
+            self.l.lerp(&rhs.l, t),
+            self.a.lerp(&rhs.a, t),
+            self.b.lerp(&rhs.b, t),
+            self.alpha.lerp(&rhs.alpha, t),
+        )
+    }
+}
+
+/// Hue takes the shorter way around the circle.
+impl Lerp for Oklch {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        let delta = (rhs.h - self.h + 180.0).rem_euclid(360.0) - 180.0;
+        Self::new(
+            self.l.lerp(&rhs.l, t),
+            self.c.lerp(&rhs.c, t),
+            (self.h + delta * t).rem_euclid(360.0),
+            self.alpha.lerp(&rhs.alpha, t),
+        )
+    }
+}
+
+impl From<Color> for Oklab {
+    fn from(color: Color) -> Self {
+        let (r, g, b) = (color.r(), color.g(), color.b());
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Self::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            color.a(),
+        )
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Self {
+        let l = oklab.l + 0.396_337_78 * oklab.a + 0.215_803_76 * oklab.b;
+        let m = oklab.l - 0.105_561_346 * oklab.a - 0.063_854_17 * oklab.b;
+        let s = oklab.l - 0.089_484_18 * oklab.a - 1.291_485_5 * oklab.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        Color::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            oklab.alpha,
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        let (c, h) = to_polar(oklab.a, oklab.b);
+        Self::new(oklab.l, c, h, oklab.alpha)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let (a, b) = from_polar(oklch.c, oklch.h);
+        Self::new(oklch.l, a, b, oklch.alpha)
+    }
+}
+
+impl From<Color> for Oklch {
+    fn from(color: Color) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        Oklab::from(oklch).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_oklab(actual: Oklab, expected: [f32; 3]) {
+        let actual_values = [actual.l, actual.a, actual.b];
+        for (a, e) in actual_values.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn known_srgb_values() {
+        assert_oklab(Oklab::from(Color::WHITE), [1.0, 0.0, 0.0]);
+        assert_oklab(Oklab::from(Color::BLACK), [0.0, 0.0, 0.0]);
+        assert_oklab(
+            Oklab::from(Color::rgb(1.0, 0.0, 0.0)),
+            [0.627_955, 0.224_863, 0.125_846],
+        );
+        assert_oklab(
+            Oklab::from(Color::rgb(0.0, 1.0, 0.0)),
+            [0.866_440, -0.233_888, 0.179_498],
+        );
+        assert_oklab(
+            Oklab::from(Color::rgb(0.0, 0.0, 1.0)),
+            [0.452_014, -0.032_457, -0.311_528],
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for color in [
+            Color::new(0.2, 0.5, 0.9, 1.0),
+            Color::new(0.9, 0.1, 0.3, 0.5),
+            Color::new(0.001, 0.002, 0.001, 1.0),
+            Color::WHITE,
+        ] {
+            for back in [
+                Color::from(Oklab::from(color)),
+                Color::from(Oklch::from(color)),
+            ] {
+                let (expected, actual): ([f32; 4], [f32; 4]) = (color.into(), back.into());
+                for (e, a) in expected.iter().zip(actual) {
+                    assert!((e - a).abs() < 1e-4, "{color:?} != {back:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oklch_lerp_takes_shorter_hue() {
+        let a = Oklch::new(0.5, 0.1, 350.0, 1.0);
+        let b = Oklch::new(0.7, 0.2, 30.0, 0.0);
+        let mid = a.lerp(&b, 0.5);
+        assert!((mid.h - 10.0).abs() < 1e-4);
+        assert!((mid.l - 0.6).abs() < 1e-6);
+        assert!((mid.c - 0.15).abs() < 1e-6);
+        assert!((mid.alpha - 0.5).abs() < 1e-6);
+        assert!((b.lerp(&a, 0.25).h - 20.0).abs() < 1e-4);
+
+        let distance = Oklab::new(0.5, 0.1, 0.0, 1.0).distance(&Oklab::new(0.5, 0.4, 0.4, 1.0));
+        assert!((distance - 0.5).abs() < 1e-6);
+    }
+}
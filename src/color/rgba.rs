@@ -0,0 +1,323 @@
+use super::{linear_to_srgb, srgb_to_linear};
+use crate::{
+    lerp::Lerp,
+    vec::{Vec3, Vec4},
+};
+use serde::Deserialize;
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string is not 3, 4, 6 or 8 hex digits long, after an optional `#`.
+    InvalidLength,
+    InvalidDigit,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseColorError::InvalidLength => write!(f, "hex color must have 3, 4, 6 or 8 digits"),
+            ParseColorError::InvalidDigit => write!(f, "invalid hex digit in color"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Linear RGBA with straight (not premultiplied) alpha, unless produced by
+/// `premultiplied`.
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct Color {
+    data: [f32; 4],
+}
+
+impl Color {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { data: [r, g, b, a] }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    /// From sRGB-encoded channels. Alpha is always linear.
+    pub fn from_srgb(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    pub fn from_srgb_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::from_srgb(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+
+    /// Parses sRGB hex in the forms `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA`,
+    /// with or without a leading `#`.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let digits = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(ParseColorError::InvalidDigit)?;
+
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits.iter().map(|d| d * 17).collect(),
+            6 | 8 => digits
+                .chunks(2)
+                .map(|pair| pair[0] * 16 + pair[1])
+                .collect(),
+            _ => return Err(ParseColorError::InvalidLength),
+        };
+        let alpha = channels.get(3).copied().unwrap_or(u8::MAX);
+
+        Ok(Self::from_srgb_u8(
+            channels[0],
+            channels[1],
+            channels[2],
+            alpha,
+        ))
+    }
+
+    pub fn r(&self) -> f32 {
+        self.data[0]
+    }
+
+    pub fn g(&self) -> f32 {
+        self.data[1]
+    }
+
+    pub fn b(&self) -> f32 {
+        self.data[2]
+    }
+
+    pub fn a(&self) -> f32 {
+        self.data[3]
+    }
+
+    pub fn with_alpha(&self, a: f32) -> Self {
+        Self::new(self.r(), self.g(), self.b(), a)
+    }
+
+    /// sRGB-encoded channels, with alpha unchanged.
+    pub fn to_srgb(&self) -> [f32; 4] {
+        [
+            linear_to_srgb(self.r()),
+            linear_to_srgb(self.g()),
+            linear_to_srgb(self.b()),
+            self.a(),
+        ]
+    }
+
+    /// sRGB-encoded channels, clamped and rounded to bytes.
+    pub fn to_srgb_u8(&self) -> [u8; 4] {
+        self.to_srgb()
+            .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// `#RRGGBBAA` in sRGB.
+    pub fn to_hex(&self) -> String {
+        let [r, g, b, a] = self.to_srgb_u8();
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+
+    pub fn clamped(&self) -> Self {
+        Self {
+            data: self.data.map(|channel| channel.clamp(0.0, 1.0)),
+        }
+    }
+
+    pub fn premultiplied(&self) -> Self {
+        let a = self.a();
+        Self::new(self.r() * a, self.g() * a, self.b() * a, a)
+    }
+
+    /// Inverse of `premultiplied`. Fully transparent colors become transparent black.
+    pub fn unpremultiplied(&self) -> Self {
+        let a = self.a();
+        if a == 0.0 {
+            return Self::TRANSPARENT;
+        }
+        Self::new(self.r() / a, self.g() / a, self.b() / a, a)
+    }
+
+    /// Rec. 709 relative luminance.
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r() + 0.7152 * self.g() + 0.0722 * self.b()
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Self {
+            data: std::array::from_fn(|idx| self.data[idx].lerp(&rhs.data[idx], t)),
+        }
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(data: [f32; 4]) -> Self {
+        Self { data }
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        color.data
+    }
+}
+
+/// Opaque.
+impl From<Vec3> for Color {
+    fn from(vec: Vec3) -> Self {
+        Self::rgb(vec.r(), vec.g(), vec.b())
+    }
+}
+
+impl From<Vec4> for Color {
+    fn from(vec: Vec4) -> Self {
+        Self::new(vec.r(), vec.g(), vec.b(), vec.a())
+    }
+}
+
+impl From<Color> for Vec3 {
+    fn from(color: Color) -> Self {
+        Vec3::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl From<Color> for Vec4 {
+    fn from(color: Color) -> Self {
+        Vec4::new(color.r(), color.g(), color.b(), color.a())
+    }
+}
+
+impl std::ops::Add<Color> for Color {
+    type Output = Self;
+
+    fn add(self, rhs: Color) -> Self::Output {
+        Self {
+            data: std::array::from_fn(|idx| self.data[idx] + rhs.data[idx]),
+        }
+    }
+}
+
+impl std::ops::Mul<Color> for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: Color) -> Self::Output {
+        Self {
+            data: std::array::from_fn(|idx| self.data[idx] * rhs.data[idx]),
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Color {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            data: self.data.map(|channel| channel * rhs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_forms() {
+        let orange = Color::from_srgb_u8(0xFF, 0x88, 0x00, 0xFF);
+        assert_eq!(Color::from_hex("#F80"), Ok(orange));
+        assert_eq!(Color::from_hex("f80"), Ok(orange));
+        assert_eq!(Color::from_hex("#ff8800"), Ok(orange));
+        assert_eq!(Color::from_hex("#FF8800FF"), Ok(orange));
+        assert_eq!(
+            Color::from_hex("#F808"),
+            Ok(Color::from_srgb_u8(0xFF, 0x88, 0x00, 0x88))
+        );
+        assert_eq!(
+            "#ff880080".parse::<Color>(),
+            Ok(orange.with_alpha(128.0 / 255.0))
+        );
+
+        assert_eq!(
+            Color::from_hex("#FF88"),
+            Ok(Color::from_srgb_u8(0xFF, 0xFF, 0x88, 0x88))
+        );
+        assert_eq!(
+            Color::from_hex("#FF880"),
+            Err(ParseColorError::InvalidLength)
+        );
+        assert_eq!(Color::from_hex(""), Err(ParseColorError::InvalidLength));
+        assert_eq!(
+            Color::from_hex("#GG8800"),
+            Err(ParseColorError::InvalidDigit)
+        );
+        assert_eq!(
+            Color::from_hex("#+f8800"),
+            Err(ParseColorError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(Color::WHITE.to_hex(), "#FFFFFFFF");
+        assert_eq!(Color::TRANSPARENT.to_string(), "#00000000");
+        for hex in ["#12345678", "#FF8800FF", "#00C0DE7F", "#80808080"] {
+            assert_eq!(Color::from_hex(hex).unwrap().to_hex(), hex);
+        }
+
+        // Out of range channels clamp rather than wrap.
+        assert_eq!(Color::new(2.0, -1.0, 0.5, 1.0).to_hex(), "#FF00BCFF");
+    }
+
+    #[test]
+    fn srgb_transfer() {
+        assert!((srgb_to_linear(0.5) - 0.214_041).abs() < 1e-6);
+        assert!((linear_to_srgb(0.5) - 0.735_357).abs() < 1e-6);
+        assert!((srgb_to_linear(0.04) - 0.04 / 12.92).abs() < 1e-7);
+        assert_eq!(srgb_to_linear(1.0), 1.0);
+        assert_eq!(linear_to_srgb(0.0), 0.0);
+
+        for byte in 0..=u8::MAX {
+            let color = Color::from_srgb_u8(byte, byte, byte, byte);
+            assert_eq!(color.to_srgb_u8(), [byte; 4]);
+        }
+    }
+
+    #[test]
+    fn premultiplied_round_trip() {
+        let color = Color::new(0.8, 0.4, 0.2, 0.5);
+        assert_eq!(color.premultiplied(), Color::new(0.4, 0.2, 0.1, 0.5));
+        assert_eq!(color.premultiplied().unpremultiplied(), color);
+        assert_eq!(
+            color.with_alpha(0.0).premultiplied().unpremultiplied(),
+            Color::TRANSPARENT
+        );
+    }
+}
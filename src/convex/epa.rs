@@ -0,0 +1,305 @@
+use super::{
+    gjk::{gjk, Gjk, SupportPoint},
+    Support,
+};
+use crate::{triangle::Triangle3, vec::Vec3};
+
+/// Polytopes converge quickly on flat-sided shapes, but curved ones such as
+/// spheres gain only a little depth per iteration, so the expansion is capped
+/// and the best face found so far is reported instead.
+const MAX_ITERATIONS: usize = 128;
+/// Relative to the depth, so large shapes converge as readily as small ones.
+const TOLERANCE: f32 = 1e-4;
+
+/// `normal` points from `a` towards `b`; moving `b` by `normal * depth`
+/// separates the shapes. The contact points are the deepest point of each
+/// shape inside the other.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Penetration {
+    pub normal: Vec3,
+    pub depth: f32,
+    pub point_a: Vec3,
+    pub point_b: Vec3,
+}
+
+struct Face {
+    indices: [usize; 3],
+    normal: Vec3,
+    distance: f32,
+}
+
+/// Penetration depth and contact normal of two overlapping convex shapes,
+/// using GJK to find an enclosing simplex and the expanding polytope
+/// algorithm to refine it. Returns `None` if the shapes are separated.
+pub fn penetration<A, B>(a: &A, b: &B) -> Option<Penetration>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let simplex = match gjk(a, b) {
+        Gjk::Separated(_) => return None,
+        Gjk::Overlapping(simplex) => simplex,
+    };
+
+    let mut vertices = match grow_to_tetrahedron(a, b, simplex.clone()) {
+        Some(vertices) => vertices,
+        // The difference is flat, so the shapes only touch.
+        None => return Some(touching(&simplex)),
+    };
+
+    // The origin may lie on the boundary of the starting tetrahedron, so
+    // faces are oriented away from its centroid instead.
+    let interior = vertices
+        .iter()
+        .fold(Vec3::zero(), |sum, vertex| sum + vertex.point)
+        * 0.25;
+
+    let mut faces = Vec::new();
+    for indices in [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]] {
+        faces.extend(Face::new(&vertices, indices, interior));
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let closest = faces
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance))
+            .map(|(i, _)| i)?;
+        let face = &faces[closest];
+
+        let next = SupportPoint::new(a, b, face.normal);
+        if next.point.dot(&face.normal) - face.distance <= TOLERANCE * face.distance.max(1.0) {
+            return Some(contact(&vertices, face));
+        }
+
+        let index = vertices.len();
+        vertices.push(next);
+
+        let mut horizon: Vec<[usize; 2]> = Vec::new();
+        faces.retain(|face| {
+            let visible = face
+                .normal
+                .dot(&(next.point - vertices[face.indices[0]].point))
+                > 0.0;
+            if visible {
+                for edge in [
+                    [face.indices[0], face.indices[1]],
+                    [face.indices[1], face.indices[2]],
+                    [face.indices[2], face.indices[0]],
+                ] {
+                    // Edges shared by two visible faces are interior to the hole.
+                    match horizon.iter().position(|e| *e == [edge[1], edge[0]]) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push(edge),
+                    }
+                }
+            }
+            !visible
+        });
+
+        for [start, end] in horizon {
+            faces.extend(Face::new(&vertices, [start, end, index], interior));
+        }
+    }
+
+    // Out of iterations: the closest face underestimates the depth, but the
+    // support distance along its normal is always enough to separate.
+    let closest = faces
+        .iter()
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))?;
+    let depth = SupportPoint::new(a, b, closest.normal)
+        .point
+        .dot(&closest.normal);
+    Some(Penetration {
+        depth,
+        ..contact(&vertices, closest)
+    })
+}
+
+impl Face {
+    /// Orients the face away from `interior`, a point inside the polytope.
+    fn new(vertices: &[SupportPoint], indices: [usize; 3], interior: Vec3) -> Option<Self> {
+        let [a, b, c] = indices.map(|i| vertices[i].point);
+        let normal = (b - a).cross(&(c - a));
+        let magnitude = normal.magnitude();
+        if magnitude <= f32::EPSILON {
+            return None;
+        }
+
+        let normal = normal / magnitude;
+        if normal.dot(&(a - interior)) < 0.0 {
+            Some(Self {
+                indices: [indices[0], indices[2], indices[1]],
+                normal: normal.negate(),
+                distance: -normal.dot(&a),
+            })
+        } else {
+            Some(Self {
+                indices,
+                normal,
+                distance: normal.dot(&a),
+            })
+        }
+    }
+}
+
+fn contact(vertices: &[SupportPoint], face: &Face) -> Penetration {
+    let [p, q, r] = face.indices.map(|i| vertices[i]);
+    let weights =
+        Triangle3::new(p.point, q.point, r.point).closest_barycentric(face.normal * face.distance);
+    let combine = |select: fn(&SupportPoint) -> Vec3| {
+        select(&p) * weights.x() + select(&q) * weights.y() + select(&r) * weights.z()
+    };
+
+    Penetration {
+        normal: face.normal,
+        depth: face.distance,
+        point_a: combine(|vertex| vertex.a),
+        point_b: combine(|vertex| vertex.b),
+    }
+}
+
+fn touching(simplex: &[SupportPoint]) -> Penetration {
+    let normal = match *simplex {
+        [p, q, r, ..] => (q.point - p.point).cross(&(r.point - p.point)),
+        [p, q] => (q.point - p.point).cross(&Vec3::new(0.0, 0.0, 1.0)),
+        _ => Vec3::zero(),
+    };
+    let normal = if normal.magnitude_squared() > f32::EPSILON {
+        normal.normalized()
+    } else {
+        Vec3::new(0.0, 1.0, 0.0)
+    };
+
+    Penetration {
+        normal,
+        depth: 0.0,
+        point_a: simplex[0].a,
+        point_b: simplex[0].b,
+    }
+}
+
+/// EPA needs a full-dimensional starting polytope, but GJK may stop on a
+/// point, segment or triangle that touches the origin.
+fn grow_to_tetrahedron<A, B>(
+    a: &A,
+    b: &B,
+    mut simplex: Vec<SupportPoint>,
+) -> Option<Vec<SupportPoint>>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let axes = [
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    ];
+
+    if simplex.len() == 1 {
+        let candidate = axes
+            .into_iter()
+            .flat_map(|axis| [axis, axis.negate()])
+            .map(|direction| SupportPoint::new(a, b, direction))
+            .find(|vertex| (vertex.point - simplex[0].point).magnitude_squared() > f32::EPSILON)?;
+        simplex.push(candidate);
+    }
+
+    if simplex.len() == 2 {
+        let edge = simplex[1].point - simplex[0].point;
+        let candidate = axes
+            .into_iter()
+            .map(|axis| edge.cross(&axis))
+            .filter(|direction| direction.magnitude_squared() > f32::EPSILON)
+            .flat_map(|direction| [direction, direction.negate(), direction.cross(&edge)])
+            .map(|direction| SupportPoint::new(a, b, direction))
+            .find(|vertex| {
+                edge.cross(&(vertex.point - simplex[0].point))
+                    .magnitude_squared()
+                    > f32::EPSILON
+            })?;
+        simplex.push(candidate);
+    }
+
+    if simplex.len() == 3 {
+        let normal =
+            (simplex[1].point - simplex[0].point).cross(&(simplex[2].point - simplex[0].point));
+        let candidate = [normal, normal.negate()]
+            .into_iter()
+            .map(|direction| SupportPoint::new(a, b, direction))
+            .find(|vertex| normal.dot(&(vertex.point - simplex[0].point)).abs() > f32::EPSILON)?;
+        simplex.push(candidate);
+    }
+
+    Some(simplex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aabb::Aabb3, sphere::Sphere};
+
+    fn assert_penetration(penetration: Penetration, normal: Vec3, depth: f32) {
+        assert!(
+            (penetration.depth - depth).abs() < 1e-3,
+            "depth {}",
+            penetration.depth
+        );
+        assert!(
+            (penetration.normal - normal).magnitude() < 1e-2,
+            "normal {:?}",
+            penetration.normal
+        );
+    }
+
+    #[test]
+    fn sphere_sphere() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let b = Sphere::new(Vec3::new(1.2, 0.0, 0.0), 1.0);
+        assert_penetration(penetration(&a, &b).unwrap(), Vec3::new(1.0, 0.0, 0.0), 0.8);
+
+        let b = Sphere::new(Vec3::new(0.0, -0.5, 0.0), 2.0);
+        assert_penetration(penetration(&a, &b).unwrap(), Vec3::new(0.0, -1.0, 0.0), 2.5);
+
+        let b = Sphere::new(Vec3::new(3.0, 0.0, 0.0), 1.0);
+        assert_eq!(penetration(&a, &b), None);
+    }
+
+    #[test]
+    fn concentric_spheres() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let penetration = penetration(&a, &a).unwrap();
+        assert!((penetration.depth - 2.0).abs() < 1e-3);
+        assert!((penetration.normal.magnitude() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn touching_spheres() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let b = Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0);
+        assert_penetration(
+            penetration(&a, &b).unwrap(),
+            Vec3::new(1.0, 0.0, 0.0),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn box_box() {
+        let a = Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let b = Aabb3::new(Vec3::new(0.5, -0.8, -0.9), Vec3::new(2.5, 1.2, 1.1));
+        assert_penetration(penetration(&a, &b).unwrap(), Vec3::new(1.0, 0.0, 0.0), 0.5);
+
+        let b = Aabb3::new(Vec3::new(-0.7, -0.9, -2.0), Vec3::new(0.9, 0.8, -0.7));
+        assert_penetration(penetration(&a, &b).unwrap(), Vec3::new(0.0, 0.0, -1.0), 0.3);
+
+        let b = Aabb3::new(Vec3::new(1.0, -1.0, -1.0), Vec3::new(3.0, 1.0, 1.0));
+        assert_penetration(
+            penetration(&a, &b).unwrap(),
+            Vec3::new(1.0, 0.0, 0.0),
+            0.0,
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use super::Support;
+use crate::{segment::Segment3, triangle::Triangle3, vec::Vec3};
+
+const MAX_ITERATIONS: usize = 64;
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ClosestPoints {
+    pub distance: f32,
+    pub point_a: Vec3,
+    pub point_b: Vec3,
+}
+
+/// A vertex of the Minkowski difference `a - b`, remembering the points of
+/// each shape that produced it so witness points can be recovered.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(super) struct SupportPoint {
+    pub point: Vec3,
+    pub a: Vec3,
+    pub b: Vec3,
+}
+
+impl SupportPoint {
+    pub fn new<A, B>(a: &A, b: &B, direction: Vec3) -> Self
+    where
+        A: Support + ?Sized,
+        B: Support + ?Sized,
+    {
+        let a = a.support(direction);
+        let b = b.support(direction.negate());
+        Self { point: a - b, a, b }
+    }
+}
+
+pub(super) enum Gjk {
+    Separated(ClosestPoints),
+    /// The final simplex, which encloses or touches the origin.
+    Overlapping(Vec<SupportPoint>),
+}
+
+pub fn intersects<A, B>(a: &A, b: &B) -> bool
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    matches!(gjk(a, b), Gjk::Overlapping(_))
+}
+
+/// Distance between two convex shapes and the closest point on each, or
+/// `None` if they overlap.
+pub fn closest_points<A, B>(a: &A, b: &B) -> Option<ClosestPoints>
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(closest) => Some(closest),
+        Gjk::Overlapping(_) => None,
+    }
+}
+
+pub(super) fn gjk<A, B>(a: &A, b: &B) -> Gjk
+where
+    A: Support + ?Sized,
+    B: Support + ?Sized,
+{
+    let first = SupportPoint::new(a, b, Vec3::new(1.0, 0.0, 0.0));
+    let mut simplex = vec![first];
+    let mut weights = vec![1.0];
+    let mut closest = first.point;
+
+    for _ in 0..MAX_ITERATIONS {
+        let distance_squared = closest.magnitude_squared();
+        if distance_squared <= EPSILON * EPSILON {
+            return Gjk::Overlapping(simplex);
+        }
+
+        let next = SupportPoint::new(a, b, closest.negate());
+        let progress = distance_squared - closest.dot(&next.point);
+        let duplicate = simplex.iter().any(|vertex| vertex.point == next.point);
+        if duplicate || progress <= EPSILON * distance_squared {
+            break;
+        }
+
+        simplex.push(next);
+        match closest_on_simplex(&simplex) {
+            Some((reduced, reduced_weights)) => {
+                simplex = reduced;
+                weights = reduced_weights;
+            }
+            None => return Gjk::Overlapping(simplex),
+        }
+        closest = combine(&simplex, &weights, |vertex| vertex.point);
+    }
+
+    Gjk::Separated(ClosestPoints {
+        distance: closest.magnitude(),
+        point_a: combine(&simplex, &weights, |vertex| vertex.a),
+        point_b: combine(&simplex, &weights, |vertex| vertex.b),
+    })
+}
+
+fn combine(
+    simplex: &[SupportPoint],
+    weights: &[f32],
+    select: impl Fn(&SupportPoint) -> Vec3,
+) -> Vec3 {
+    simplex
+        .iter()
+        .zip(weights)
+        .fold(Vec3::zero(), |sum, (vertex, weight)| {
+            sum + select(vertex) * *weight
+        })
+}
+
+/// Reduces the simplex to the smallest sub-simplex containing the point
+/// closest to the origin, with that point's barycentric weights. Returns
+/// `None` when the origin is inside a tetrahedron.
+fn closest_on_simplex(simplex: &[SupportPoint]) -> Option<(Vec<SupportPoint>, Vec<f32>)> {
+    match *simplex {
+        [a] => Some((vec![a], vec![1.0])),
+        [a, b] => {
+            let t = Segment3::new(a.point, b.point).closest_t(Vec3::zero());
+            Some(reduce(&[a, b], &[1.0 - t, t]))
+        }
+        [a, b, c] => {
+            let weights =
+                Triangle3::new(a.point, b.point, c.point).closest_barycentric(Vec3::zero());
+            Some(reduce(&[a, b, c], &[weights.x(), weights.y(), weights.z()]))
+        }
+        [a, b, c, d] => {
+            let faces = [[a, b, c, d], [a, c, d, b], [a, d, b, c], [b, d, c, a]];
+            let mut best: Option<(Vec<SupportPoint>, Vec<f32>, f32)> = None;
+            for [p, q, r, opposite] in faces {
+                let normal = (q.point - p.point).cross(&(r.point - p.point));
+                let origin_side = normal.dot(&p.point.negate());
+                let opposite_side = normal.dot(&(opposite.point - p.point));
+                if origin_side * opposite_side > 0.0 {
+                    continue;
+                }
+
+                let (face, weights) = closest_on_simplex(&[p, q, r])?;
+                let distance_squared =
+                    combine(&face, &weights, |vertex| vertex.point).magnitude_squared();
+                if distance_squared < best.as_ref().map_or(f32::INFINITY, |best| best.2) {
+                    best = Some((face, weights, distance_squared));
+                }
+            }
+            best.map(|(face, weights, _)| (face, weights))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn reduce(simplex: &[SupportPoint], weights: &[f32]) -> (Vec<SupportPoint>, Vec<f32>) {
+    simplex
+        .iter()
+        .zip(weights)
+        .filter(|(_, weight)| **weight > 0.0)
+        .map(|(vertex, weight)| (*vertex, *weight))
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aabb::Aabb3, sphere::Sphere};
+
+    #[test]
+    fn sphere_distance() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let b = Sphere::new(Vec3::new(0.0, 4.0, 0.0), 2.0);
+        let closest = closest_points(&a, &b).unwrap();
+        assert!((closest.distance - 1.0).abs() < 1e-4);
+        assert!((closest.point_a - Vec3::new(0.0, 1.0, 0.0)).magnitude() < 1e-2);
+        assert!((closest.point_b - Vec3::new(0.0, 2.0, 0.0)).magnitude() < 1e-2);
+        assert!(!intersects(&a, &b));
+
+        let b = Sphere::new(Vec3::new(0.0, 2.5, 0.0), 2.0);
+        assert_eq!(closest_points(&a, &b), None);
+        assert!(intersects(&a, &b));
+    }
+
+    #[test]
+    fn box_distance() {
+        let a = Aabb3::new(Vec3::zero(), Vec3::new(1.0, 1.0, 1.0));
+        let b = Aabb3::new(Vec3::new(3.0, 4.0, 0.5), Vec3::new(5.0, 6.0, 2.0));
+        let closest = closest_points(&a, &b).unwrap();
+        // Corner (1, 1, z) to corner (3, 4, z) across the overlapping z range.
+        assert!((closest.distance - 13f32.sqrt()).abs() < 1e-4);
+        assert!((closest.point_b - closest.point_a).magnitude() - closest.distance < 1e-4);
+
+        let b = Aabb3::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(2.0, 2.0, 2.0));
+        assert_eq!(closest_points(&a, &b), None);
+    }
+
+    #[test]
+    fn touching_spheres() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let b = Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0);
+        assert_eq!(closest_points(&a, &b), None);
+        assert!(intersects(&a, &b));
+    }
+}
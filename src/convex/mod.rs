@@ -0,0 +1,9 @@
+mod epa;
+mod gjk;
+mod support;
+
+pub use self::{
+    epa::{penetration, Penetration},
+    gjk::{closest_points, intersects, ClosestPoints},
+    support::{MinkowskiSum, Support},
+};
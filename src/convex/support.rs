@@ -0,0 +1,100 @@
+use crate::{
+    aabb::Aabb3, capsule::Capsule, hull::ConvexHull3, obb::Obb3, sphere::Sphere, vec::Vec3,
+};
+
+/// A convex shape described by its support mapping: the point of the shape
+/// furthest along a given direction.
+pub trait Support {
+    fn support(&self, direction: Vec3) -> Vec3;
+}
+
+impl<T: Support + ?Sized> Support for &T {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        (**self).support(direction)
+    }
+}
+
+impl Support for Sphere {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        let magnitude = direction.magnitude();
+        if magnitude == 0.0 {
+            return self.center();
+        }
+        self.center() + direction * (self.radius() / magnitude)
+    }
+}
+
+impl Support for Aabb3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        let select = |axis: usize| {
+            if direction.idx(axis) >= 0.0 {
+                self.max().idx(axis)
+            } else {
+                self.min().idx(axis)
+            }
+        };
+        Vec3::new(select(0), select(1), select(2))
+    }
+}
+
+impl Support for Obb3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        let local = self.rotation().transposed() * direction;
+        let half_extents = self.half_extents();
+        let select = |axis: usize| {
+            if local.idx(axis) >= 0.0 {
+                half_extents.idx(axis)
+            } else {
+                -half_extents.idx(axis)
+            }
+        };
+        self.to_world(Vec3::new(select(0), select(1), select(2)))
+    }
+}
+
+impl Support for Capsule {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        let end = if self.end().dot(&direction) > self.start().dot(&direction) {
+            self.end()
+        } else {
+            self.start()
+        };
+        Sphere::new(end, self.radius()).support(direction)
+    }
+}
+
+/// The convex hull of a point cloud.
+impl Support for [Vec3] {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        self.iter()
+            .copied()
+            .max_by(|a, b| a.dot(&direction).total_cmp(&b.dot(&direction)))
+            .unwrap_or_default()
+    }
+}
+
+impl Support for ConvexHull3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        self.vertices().support(direction)
+    }
+}
+
+/// Every `a + b` for `a` in the first shape and `b` in the second, such as a
+/// box swept by a sphere to get a rounded box.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Support, B: Support> MinkowskiSum<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: Support, B: Support> Support for MinkowskiSum<A, B> {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        self.a.support(direction) + self.b.support(direction)
+    }
+}
@@ -0,0 +1,122 @@
+use super::CurvePoint;
+
+/// Maps distance along a curve back to its parameter, so a curve can be
+/// traversed at constant speed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    start: f32,
+    end: f32,
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Samples `curve` at `samples + 1` evenly spaced parameters between `start` and `end`.
+    pub fn new<T: CurvePoint>(
+        curve: impl Fn(f32) -> T,
+        start: f32,
+        end: f32,
+        samples: usize,
+    ) -> Self {
+        let samples = samples.max(1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut previous = curve(start);
+        let mut total = 0.0;
+        lengths.push(0.0);
+
+        for i in 1..=samples {
+            let point = curve(start + (end - start) * (i as f32 / samples as f32));
+            total += (point - previous).magnitude();
+            lengths.push(total);
+            previous = point;
+        }
+
+        Self {
+            start,
+            end,
+            lengths,
+        }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.0)
+    }
+
+    /// Parameter at `distance` along the curve, clamped to the curve's ends.
+    /// An empty table, as made by `default`, always gives `start`.
+    pub fn parameter(&self, distance: f32) -> f32 {
+        if self.lengths.len() < 2 {
+            return self.start;
+        }
+        let distance = distance.clamp(0.0, self.length());
+        let upper = self
+            .lengths
+            .partition_point(|length| *length < distance)
+            .clamp(1, self.lengths.len() - 1);
+        let (low, high) = (self.lengths[upper - 1], self.lengths[upper]);
+
+        let fraction = if high > low {
+            (distance - low) / (high - low)
+        } else {
+            0.0
+        };
+        let segments = (self.lengths.len() - 1) as f32;
+        self.start + (self.end - self.start) * ((upper - 1) as f32 + fraction) / segments
+    }
+
+    /// Parameter at a `fraction` in `[0, 1]` of the total length.
+    pub fn parameter_at_fraction(&self, fraction: f32) -> f32 {
+        self.parameter(fraction * self.length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{curve::CubicBezier, vec::Vec2};
+
+    #[test]
+    fn straight_line() {
+        let table = ArcLengthTable::new(|t| Vec2::new(4.0 * t, 3.0 * t), 0.0, 2.0, 8);
+        assert!((table.length() - 10.0).abs() < 1e-5);
+        assert!((table.parameter(5.0) - 1.0).abs() < 1e-5);
+        assert!((table.parameter_at_fraction(0.25) - 0.5).abs() < 1e-5);
+        assert_eq!(table.parameter(-1.0), 0.0);
+        assert_eq!(table.parameter(20.0), 2.0);
+    }
+
+    #[test]
+    fn parameters_increase_with_distance() {
+        let bezier = CubicBezier::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.1, 3.0),
+            Vec2::new(4.0, 3.0),
+            Vec2::new(5.0, 0.0),
+        );
+        let table = ArcLengthTable::new(|t| bezier.evaluate(t), 0.0, 1.0, 64);
+        assert_eq!(table.parameter(0.0), 0.0);
+        assert!((table.parameter(table.length()) - 1.0).abs() < 1e-6);
+
+        let parameters: Vec<f32> = (0..=100)
+            .map(|i| table.parameter_at_fraction(i as f32 / 100.0))
+            .collect();
+        assert!(parameters.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Equal steps in distance cover roughly equal chords.
+        let chords: Vec<f32> = parameters
+            .windows(2)
+            .map(|pair| (bezier.evaluate(pair[1]) - bezier.evaluate(pair[0])).magnitude())
+            .collect();
+        let step = table.length() / 100.0;
+        assert!(chords
+            .iter()
+            .all(|chord| (chord - step).abs() < 0.05 * step));
+    }
+
+    #[test]
+    fn empty_table() {
+        let table = ArcLengthTable::default();
+        assert_eq!(table.length(), 0.0);
+        assert_eq!(table.parameter(1.0), 0.0);
+        assert_eq!(table.parameter_at_fraction(0.5), 0.0);
+    }
+}
@@ -0,0 +1,225 @@
+use super::CurvePoint;
+use serde::Deserialize;
+
+/// Subdivision depth limit when flattening, which bounds the output to
+/// `2^MAX_DEPTH` segments per curve.
+const MAX_DEPTH: u32 = 16;
+
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct QuadraticBezier<T> {
+    p0: T,
+    p1: T,
+    p2: T,
+}
+
+impl<T: CurvePoint> QuadraticBezier<T> {
+    pub fn new(p0: T, p1: T, p2: T) -> Self {
+        Self { p0, p1, p2 }
+    }
+
+    pub fn points(&self) -> [T; 3] {
+        [self.p0, self.p1, self.p2]
+    }
+
+    pub fn evaluate(&self, t: f32) -> T {
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+
+    pub fn derivative(&self, t: f32) -> T {
+        (self.p1 - self.p0) * (2.0 * (1.0 - t)) + (self.p2 - self.p1) * (2.0 * t)
+    }
+
+    /// de Casteljau subdivision at `t`.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let p01 = lerp(self.p0, self.p1, t);
+        let p12 = lerp(self.p1, self.p2, t);
+        let mid = lerp(p01, p12, t);
+        (Self::new(self.p0, p01, mid), Self::new(mid, p12, self.p2))
+    }
+
+    pub fn elevate(&self) -> CubicBezier<T> {
+        CubicBezier::new(
+            self.p0,
+            self.p0 + (self.p1 - self.p0) * (2.0 / 3.0),
+            self.p2 + (self.p1 - self.p2) * (2.0 / 3.0),
+            self.p2,
+        )
+    }
+
+    /// Tight bounds, found from the roots of the derivative on each axis.
+    pub fn bounding_box(&self) -> T::Bounds {
+        let mut points = vec![self.p0, self.p2];
+        for axis in 0..T::DIMENSIONS {
+            let (a, b, c) = (self.p0.idx(axis), self.p1.idx(axis), self.p2.idx(axis));
+            let denom = a - 2.0 * b + c;
+            if denom != 0.0 {
+                let t = (a - b) / denom;
+                if t > 0.0 && t < 1.0 {
+                    points.push(self.evaluate(t));
+                }
+            }
+        }
+        T::bounds(&points)
+    }
+
+    pub fn length(&self) -> f32 {
+        self.elevate().length()
+    }
+
+    /// Polyline within `tolerance` of the curve, including both endpoints.
+    pub fn flatten(&self, tolerance: f32) -> Vec<T> {
+        let mut points = vec![self.p0];
+        self.flatten_into(tolerance, MAX_DEPTH, &mut points);
+        points
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, points: &mut Vec<T>) {
+        // The curve strays at most half as far from the chord as the control point.
+        let deviation = (self.p1 - lerp(self.p0, self.p2, 0.5)).magnitude() * 0.5;
+        if depth == 0 || deviation <= tolerance {
+            points.push(self.p2);
+            return;
+        }
+
+        let (left, right) = self.split(0.5);
+        left.flatten_into(tolerance, depth - 1, points);
+        right.flatten_into(tolerance, depth - 1, points);
+    }
+}
+
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct CubicBezier<T> {
+    p0: T,
+    p1: T,
+    p2: T,
+    p3: T,
+}
+
+impl<T: CurvePoint> CubicBezier<T> {
+    pub fn new(p0: T, p1: T, p2: T, p3: T) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    pub fn points(&self) -> [T; 4] {
+        [self.p0, self.p1, self.p2, self.p3]
+    }
+
+    pub fn evaluate(&self, t: f32) -> T {
+        let s = 1.0 - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (3.0 * s * s * t)
+            + self.p2 * (3.0 * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    pub fn derivative(&self, t: f32) -> T {
+        let s = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * s * s)
+            + (self.p2 - self.p1) * (6.0 * s * t)
+            + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+
+    pub fn second_derivative(&self, t: f32) -> T {
+        let first = self.p2 - self.p1 * 2.0 + self.p0;
+        let second = self.p3 - self.p2 * 2.0 + self.p1;
+        first * (6.0 * (1.0 - t)) + second * (6.0 * t)
+    }
+
+    /// de Casteljau subdivision at `t`.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let p01 = lerp(self.p0, self.p1, t);
+        let p12 = lerp(self.p1, self.p2, t);
+        let p23 = lerp(self.p2, self.p3, t);
+        let p012 = lerp(p01, p12, t);
+        let p123 = lerp(p12, p23, t);
+        let mid = lerp(p012, p123, t);
+        (
+            Self::new(self.p0, p01, p012, mid),
+            Self::new(mid, p123, p23, self.p3),
+        )
+    }
+
+    /// Tight bounds, found from the roots of the derivative on each axis.
+    pub fn bounding_box(&self) -> T::Bounds {
+        let mut points = vec![self.p0, self.p3];
+        for axis in 0..T::DIMENSIONS {
+            let (p0, p1, p2, p3) = (
+                self.p0.idx(axis),
+                self.p1.idx(axis),
+                self.p2.idx(axis),
+                self.p3.idx(axis),
+            );
+            let a = p3 - 3.0 * p2 + 3.0 * p1 - p0;
+            let b = 2.0 * (p2 - 2.0 * p1 + p0);
+            let c = p1 - p0;
+
+            let mut roots = Vec::with_capacity(2);
+            if a.abs() < 1e-12 {
+                if b != 0.0 {
+                    roots.push(-c / b);
+                }
+            } else {
+                let discriminant = b * b - 4.0 * a * c;
+                if discriminant >= 0.0 {
+                    let sqrt_discriminant = discriminant.sqrt();
+                    roots.push((-b + sqrt_discriminant) / (2.0 * a));
+                    roots.push((-b - sqrt_discriminant) / (2.0 * a));
+                }
+            }
+
+            for t in roots {
+                if t > 0.0 && t < 1.0 {
+                    points.push(self.evaluate(t));
+                }
+            }
+        }
+        T::bounds(&points)
+    }
+
+    /// Arc length by 8-point Gauss–Legendre quadrature of the speed.
+    pub fn length(&self) -> f32 {
+        const NODES: [(f32, f32); 4] = [
+            (0.183_434_64, 0.362_683_78),
+            (0.525_532_4, 0.313_706_65),
+            (0.796_666_5, 0.222_381_03),
+            (0.960_289_9, 0.101_228_54),
+        ];
+
+        NODES
+            .iter()
+            .map(|(x, weight)| {
+                let low = self.derivative(0.5 - 0.5 * x).magnitude();
+                let high = self.derivative(0.5 + 0.5 * x).magnitude();
+                (low + high) * weight
+            })
+            .sum::<f32>()
+            * 0.5
+    }
+
+    /// Polyline within `tolerance` of the curve, including both endpoints.
+    pub fn flatten(&self, tolerance: f32) -> Vec<T> {
+        let mut points = vec![self.p0];
+        self.flatten_into(tolerance, MAX_DEPTH, &mut points);
+        points
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, points: &mut Vec<T>) {
+        // Control points close to the chord's thirds bound the curve's deviation.
+        let deviation = (self.p1 - lerp(self.p0, self.p3, 1.0 / 3.0))
+            .magnitude()
+            .max((self.p2 - lerp(self.p0, self.p3, 2.0 / 3.0)).magnitude());
+        if depth == 0 || deviation <= tolerance {
+            points.push(self.p3);
+            return;
+        }
+
+        let (left, right) = self.split(0.5);
+        left.flatten_into(tolerance, depth - 1, points);
+        right.flatten_into(tolerance, depth - 1, points);
+    }
+}
+
+fn lerp<T: CurvePoint>(a: T, b: T, t: f32) -> T {
+    a + (b - a) * t
+}
@@ -0,0 +1,67 @@
+mod arc_length;
+mod bezier;
+mod spline;
+
+pub use self::{
+    arc_length::ArcLengthTable,
+    bezier::{CubicBezier, QuadraticBezier},
+    spline::{BSpline, CatmullRom, CatmullRomKind, Hermite},
+};
+
+use crate::{
+    aabb::{Aabb2, Aabb3},
+    vec::{Vec2, Vec3},
+};
+
+/// Vector types that curves can be built over.
+pub trait CurvePoint:
+    Copy
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<f32, Output = Self>
+{
+    type Bounds;
+    const DIMENSIONS: usize;
+
+    fn idx(&self, idx: usize) -> f32;
+    fn dot(&self, rhs: &Self) -> f32;
+    fn bounds(points: &[Self]) -> Self::Bounds;
+
+    fn magnitude(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl CurvePoint for Vec2 {
+    type Bounds = Aabb2;
+    const DIMENSIONS: usize = 2;
+
+    fn idx(&self, idx: usize) -> f32 {
+        Vec2::idx(self, idx)
+    }
+
+    fn dot(&self, rhs: &Self) -> f32 {
+        Vec2::dot(self, rhs)
+    }
+
+    fn bounds(points: &[Self]) -> Aabb2 {
+        Aabb2::from_points(points).unwrap_or_default()
+    }
+}
+
+impl CurvePoint for Vec3 {
+    type Bounds = Aabb3;
+    const DIMENSIONS: usize = 3;
+
+    fn idx(&self, idx: usize) -> f32 {
+        Vec3::idx(self, idx)
+    }
+
+    fn dot(&self, rhs: &Self) -> f32 {
+        Vec3::dot(self, rhs)
+    }
+
+    fn bounds(points: &[Self]) -> Aabb3 {
+        Aabb3::from_points(points).unwrap_or_default()
+    }
+}
@@ -0,0 +1,369 @@
+use super::{CubicBezier, CurvePoint};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Hermite<T> {
+    p0: T,
+    m0: T,
+    p1: T,
+    m1: T,
+}
+
+impl<T: CurvePoint> Hermite<T> {
+    /// Curve from `p0` to `p1` with tangents `m0` and `m1` at either end.
+    pub fn new(p0: T, m0: T, p1: T, m1: T) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+
+    pub fn evaluate(&self, t: f32) -> T {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        self.p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.m0 * (t3 - 2.0 * t2 + t)
+            + self.p1 * (-2.0 * t3 + 3.0 * t2)
+            + self.m1 * (t3 - t2)
+    }
+
+    pub fn derivative(&self, t: f32) -> T {
+        let t2 = t * t;
+        self.p0 * (6.0 * t2 - 6.0 * t)
+            + self.m0 * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.p1 * (-6.0 * t2 + 6.0 * t)
+            + self.m1 * (3.0 * t2 - 2.0 * t)
+    }
+
+    pub fn to_bezier(&self) -> CubicBezier<T> {
+        CubicBezier::new(
+            self.p0,
+            self.p0 + self.m0 * (1.0 / 3.0),
+            self.p1 - self.m1 * (1.0 / 3.0),
+            self.p1,
+        )
+    }
+}
+
+/// Knot spacing of a Catmull-Rom spline. Centripetal avoids the cusps and
+/// self-intersections the uniform variant produces on uneven control points.
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CatmullRomKind {
+    #[default]
+    Uniform,
+    Centripetal,
+    Chordal,
+}
+
+impl CatmullRomKind {
+    fn alpha(self) -> f32 {
+        match self {
+            CatmullRomKind::Uniform => 0.0,
+            CatmullRomKind::Centripetal => 0.5,
+            CatmullRomKind::Chordal => 1.0,
+        }
+    }
+}
+
+/// Interpolating spline through every control point except the first and
+/// last, which only shape the end tangents. Segment `i` runs from
+/// `points[i + 1]` to `points[i + 2]`.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "RawCatmullRom<T>")]
+pub struct CatmullRom<T> {
+    points: Vec<T>,
+    kind: CatmullRomKind,
+}
+
+/// Deserialized fields, checked to hold at least one segment.
+#[derive(Deserialize)]
+struct RawCatmullRom<T> {
+    points: Vec<T>,
+    #[serde(default)]
+    kind: CatmullRomKind,
+}
+
+impl<T> TryFrom<RawCatmullRom<T>> for CatmullRom<T> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawCatmullRom<T>) -> Result<Self, Self::Error> {
+        check_point_count(raw.points.len())?;
+        Ok(Self {
+            points: raw.points,
+            kind: raw.kind,
+        })
+    }
+}
+
+impl<T: CurvePoint> CatmullRom<T> {
+    /// Panics if there are fewer than four points.
+    pub fn new(points: Vec<T>, kind: CatmullRomKind) -> Self {
+        assert!(points.len() >= 4, "{TOO_FEW_POINTS}");
+        Self { points, kind }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn kind(&self) -> CatmullRomKind {
+        self.kind
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.points.len().saturating_sub(3)
+    }
+
+    /// Panics if `idx` is not below [`Self::segment_count`].
+    pub fn segment(&self, idx: usize) -> Hermite<T> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| self.points[idx + i]);
+        let alpha = self.kind.alpha();
+        let knot = |a: T, b: T| (b - a).magnitude().powf(alpha).max(1e-6);
+        let (d0, d1, d2) = (knot(p0, p1), knot(p1, p2), knot(p2, p3));
+
+        let m1 =
+            ((p1 - p0) * (1.0 / d0) - (p2 - p0) * (1.0 / (d0 + d1)) + (p2 - p1) * (1.0 / d1)) * d1;
+        let m2 =
+            ((p2 - p1) * (1.0 / d1) - (p3 - p1) * (1.0 / (d1 + d2)) + (p3 - p2) * (1.0 / d2)) * d1;
+        Hermite::new(p1, m1, p2, m2)
+    }
+
+    /// `t` runs from 0 to `segment_count()`, one unit per segment.
+    pub fn evaluate(&self, t: f32) -> T {
+        let (idx, local) = locate(t, self.segment_count());
+        self.segment(idx).evaluate(local)
+    }
+
+    pub fn derivative(&self, t: f32) -> T {
+        let (idx, local) = locate(t, self.segment_count());
+        self.segment(idx).derivative(local)
+    }
+
+    pub fn to_beziers(&self) -> Vec<CubicBezier<T>> {
+        (0..self.segment_count())
+            .map(|idx| self.segment(idx).to_bezier())
+            .collect()
+    }
+
+    pub fn flatten(&self, tolerance: f32) -> Vec<T> {
+        flatten_beziers(&self.to_beziers(), tolerance)
+    }
+}
+
+/// Uniform cubic B-spline. It is C2 continuous but only approximates its
+/// control points. Segment `i` is shaped by `points[i..i + 4]`.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "Vec<T>")]
+pub struct BSpline<T> {
+    points: Vec<T>,
+}
+
+impl<T> TryFrom<Vec<T>> for BSpline<T> {
+    type Error = &'static str;
+
+    fn try_from(points: Vec<T>) -> Result<Self, Self::Error> {
+        check_point_count(points.len())?;
+        Ok(Self { points })
+    }
+}
+
+impl<T: CurvePoint> BSpline<T> {
+    /// Panics if there are fewer than four points.
+    pub fn new(points: Vec<T>) -> Self {
+        assert!(points.len() >= 4, "{TOO_FEW_POINTS}");
+        Self { points }
+    }
+
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.points.len().saturating_sub(3)
+    }
+
+    /// Panics if `idx` is not below [`Self::segment_count`].
+    pub fn segment(&self, idx: usize) -> CubicBezier<T> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| self.points[idx + i]);
+        CubicBezier::new(
+            (p0 + p1 * 4.0 + p2) * (1.0 / 6.0),
+            p1 + (p2 - p1) * (1.0 / 3.0),
+            p1 + (p2 - p1) * (2.0 / 3.0),
+            (p1 + p2 * 4.0 + p3) * (1.0 / 6.0),
+        )
+    }
+
+    /// `t` runs from 0 to `segment_count()`, one unit per segment.
+    pub fn evaluate(&self, t: f32) -> T {
+        let (idx, local) = locate(t, self.segment_count());
+        self.segment(idx).evaluate(local)
+    }
+
+    pub fn derivative(&self, t: f32) -> T {
+        let (idx, local) = locate(t, self.segment_count());
+        self.segment(idx).derivative(local)
+    }
+
+    pub fn to_beziers(&self) -> Vec<CubicBezier<T>> {
+        (0..self.segment_count())
+            .map(|idx| self.segment(idx))
+            .collect()
+    }
+
+    pub fn flatten(&self, tolerance: f32) -> Vec<T> {
+        flatten_beziers(&self.to_beziers(), tolerance)
+    }
+}
+
+const TOO_FEW_POINTS: &str = "spline needs at least four points";
+
+fn check_point_count(len: usize) -> Result<(), &'static str> {
+    if len < 4 {
+        return Err(TOO_FEW_POINTS);
+    }
+    Ok(())
+}
+
+/// Splits a spline parameter into a segment index and the parameter within
+/// that segment. Construction guarantees at least one segment.
+fn locate(t: f32, segment_count: usize) -> (usize, f32) {
+    let t = t.clamp(0.0, segment_count as f32);
+    let idx = (t.floor() as usize).min(segment_count - 1);
+    (idx, t - idx as f32)
+}
+
+fn flatten_beziers<T: CurvePoint>(beziers: &[CubicBezier<T>], tolerance: f32) -> Vec<T> {
+    let mut points = Vec::new();
+    for (idx, bezier) in beziers.iter().enumerate() {
+        let flattened = bezier.flatten(tolerance);
+        let skip = if idx == 0 { 0 } else { 1 };
+        points.extend(flattened.into_iter().skip(skip));
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec::Vec2;
+
+    fn points() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(3.0, 3.0),
+            Vec2::new(4.0, 1.0),
+            Vec2::new(6.0, 0.5),
+            Vec2::new(7.0, 4.0),
+        ]
+    }
+
+    fn assert_vec(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected).magnitude() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn hermite_endpoints() {
+        let p0 = Vec2::new(1.0, 2.0);
+        let m0 = Vec2::new(3.0, 0.0);
+        let p1 = Vec2::new(4.0, -1.0);
+        let m1 = Vec2::new(0.0, 2.0);
+        let hermite = Hermite::new(p0, m0, p1, m1);
+        assert_vec(hermite.evaluate(0.0), p0);
+        assert_vec(hermite.evaluate(1.0), p1);
+        assert_vec(hermite.derivative(0.0), m0);
+        assert_vec(hermite.derivative(1.0), m1);
+
+        let bezier = hermite.to_bezier();
+        for t in [0.0, 0.3, 0.5, 1.0] {
+            assert_vec(bezier.evaluate(t), hermite.evaluate(t));
+        }
+    }
+
+    #[test]
+    fn catmull_rom_interpolates_inner_points() {
+        for kind in [
+            CatmullRomKind::Uniform,
+            CatmullRomKind::Centripetal,
+            CatmullRomKind::Chordal,
+        ] {
+            let spline = CatmullRom::new(points(), kind);
+            assert_eq!(spline.segment_count(), 3);
+            for (t, point) in points()[1..5].iter().enumerate() {
+                assert_vec(spline.evaluate(t as f32), *point);
+            }
+            // Parameters outside the curve clamp to its ends.
+            assert_vec(spline.evaluate(-1.0), points()[1]);
+            assert_vec(spline.evaluate(10.0), points()[4]);
+        }
+    }
+
+    #[test]
+    fn tangents_are_continuous_across_segments() {
+        let spline = CatmullRom::new(points(), CatmullRomKind::Uniform);
+        for joint in 1..spline.segment_count() {
+            assert_vec(
+                spline.segment(joint - 1).derivative(1.0),
+                spline.segment(joint).derivative(0.0),
+            );
+        }
+
+        // Segments are parameterized by their own knot interval, so only
+        // the tangent direction carries across.
+        for kind in [CatmullRomKind::Centripetal, CatmullRomKind::Chordal] {
+            let spline = CatmullRom::new(points(), kind);
+            for joint in 1..spline.segment_count() {
+                assert_vec(
+                    spline.segment(joint - 1).derivative(1.0).normalized(),
+                    spline.segment(joint).derivative(0.0).normalized(),
+                );
+            }
+        }
+
+        let spline = BSpline::new(points());
+        for joint in 1..spline.segment_count() {
+            let (before, after) = (spline.segment(joint - 1), spline.segment(joint));
+            assert_vec(before.evaluate(1.0), after.evaluate(0.0));
+            assert_vec(before.derivative(1.0), after.derivative(0.0));
+            assert_vec(before.second_derivative(1.0), after.second_derivative(0.0));
+        }
+    }
+
+    #[test]
+    fn b_spline_endpoints() {
+        let points = points();
+        let spline = BSpline::new(points.clone());
+        assert_vec(
+            spline.evaluate(0.0),
+            (points[0] + points[1] * 4.0 + points[2]) * (1.0 / 6.0),
+        );
+        assert_vec(
+            spline.evaluate(3.0),
+            (points[3] + points[4] * 4.0 + points[5]) * (1.0 / 6.0),
+        );
+
+        // Collinear, evenly spaced control points give a straight line at
+        // constant speed.
+        let line = BSpline::new((0..5).map(|i| Vec2::new(i as f32, 0.0)).collect());
+        assert_vec(line.evaluate(0.5), Vec2::new(1.5, 0.0));
+        assert_vec(line.derivative(1.3), Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn too_few_points() {
+        let three = points()[..3].to_vec();
+        assert!(BSpline::try_from(three.clone()).is_err());
+        assert!(BSpline::try_from(points()).is_ok());
+        let raw = RawCatmullRom {
+            points: three,
+            kind: CatmullRomKind::Uniform,
+        };
+        assert!(CatmullRom::try_from(raw).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least four points")]
+    fn new_rejects_too_few_points() {
+        CatmullRom::new(points()[..3].to_vec(), CatmullRomKind::Centripetal);
+    }
+}
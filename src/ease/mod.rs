@@ -0,0 +1,286 @@
+//! Robert Penner's easing equations, mapping normalized time in `[0, 1]` to
+//! progress. Every curve starts at 0 and ends at 1; back and elastic overshoot
+//! in between.
+
+use serde::Deserialize;
+use std::f32::consts::PI;
+
+const BACK: f32 = 1.70158;
+const BACK_IN_OUT: f32 = BACK * 1.525;
+const ELASTIC: f32 = 2.0 * PI / 3.0;
+const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => quad_in(t),
+            Easing::QuadOut => quad_out(t),
+            Easing::QuadInOut => quad_in_out(t),
+            Easing::CubicIn => cubic_in(t),
+            Easing::CubicOut => cubic_out(t),
+            Easing::CubicInOut => cubic_in_out(t),
+            Easing::QuartIn => quart_in(t),
+            Easing::QuartOut => quart_out(t),
+            Easing::QuartInOut => quart_in_out(t),
+            Easing::QuintIn => quint_in(t),
+            Easing::QuintOut => quint_out(t),
+            Easing::QuintInOut => quint_in_out(t),
+            Easing::SineIn => sine_in(t),
+            Easing::SineOut => sine_out(t),
+            Easing::SineInOut => sine_in_out(t),
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => expo_out(t),
+            Easing::ExpoInOut => expo_in_out(t),
+            Easing::CircIn => circ_in(t),
+            Easing::CircOut => circ_out(t),
+            Easing::CircInOut => circ_in_out(t),
+            Easing::BackIn => back_in(t),
+            Easing::BackOut => back_out(t),
+            Easing::BackInOut => back_in_out(t),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => elastic_out(t),
+            Easing::ElasticInOut => elastic_in_out(t),
+            Easing::BounceIn => bounce_in(t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => bounce_in_out(t),
+        }
+    }
+}
+
+fn power_in(t: f32, n: i32) -> f32 {
+    t.powi(n)
+}
+
+fn power_out(t: f32, n: i32) -> f32 {
+    1.0 - (1.0 - t).powi(n)
+}
+
+fn power_in_out(t: f32, n: i32) -> f32 {
+    if t < 0.5 {
+        2f32.powi(n - 1) * t.powi(n)
+    } else {
+        1.0 - (2.0 - 2.0 * t).powi(n) / 2.0
+    }
+}
+
+pub fn quad_in(t: f32) -> f32 {
+    power_in(t, 2)
+}
+
+pub fn quad_out(t: f32) -> f32 {
+    power_out(t, 2)
+}
+
+pub fn quad_in_out(t: f32) -> f32 {
+    power_in_out(t, 2)
+}
+
+pub fn cubic_in(t: f32) -> f32 {
+    power_in(t, 3)
+}
+
+pub fn cubic_out(t: f32) -> f32 {
+    power_out(t, 3)
+}
+
+pub fn cubic_in_out(t: f32) -> f32 {
+    power_in_out(t, 3)
+}
+
+pub fn quart_in(t: f32) -> f32 {
+    power_in(t, 4)
+}
+
+pub fn quart_out(t: f32) -> f32 {
+    power_out(t, 4)
+}
+
+pub fn quart_in_out(t: f32) -> f32 {
+    power_in_out(t, 4)
+}
+
+pub fn quint_in(t: f32) -> f32 {
+    power_in(t, 5)
+}
+
+pub fn quint_out(t: f32) -> f32 {
+    power_out(t, 5)
+}
+
+pub fn quint_in_out(t: f32) -> f32 {
+    power_in_out(t, 5)
+}
+
+pub fn sine_in(t: f32) -> f32 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+pub fn sine_out(t: f32) -> f32 {
+    (t * PI / 2.0).sin()
+}
+
+pub fn sine_in_out(t: f32) -> f32 {
+    -((PI * t).cos() - 1.0) / 2.0
+}
+
+pub fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2f32.powf(10.0 * t - 10.0)
+    }
+}
+
+pub fn expo_out(t: f32) -> f32 {
+    if t >= 1.0 {
+        1.0
+    } else {
+        1.0 - 2f32.powf(-10.0 * t)
+    }
+}
+
+pub fn expo_in_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        2f32.powf(20.0 * t - 10.0) / 2.0
+    } else {
+        (2.0 - 2f32.powf(-20.0 * t + 10.0)) / 2.0
+    }
+}
+
+pub fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).max(0.0).sqrt()
+}
+
+pub fn circ_out(t: f32) -> f32 {
+    (1.0 - (t - 1.0) * (t - 1.0)).max(0.0).sqrt()
+}
+
+pub fn circ_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - (1.0 - (2.0 * t).powi(2)).max(0.0).sqrt()) / 2.0
+    } else {
+        ((1.0 - (2.0 - 2.0 * t).powi(2)).max(0.0).sqrt() + 1.0) / 2.0
+    }
+}
+
+pub fn back_in(t: f32) -> f32 {
+    (BACK + 1.0) * t * t * t - BACK * t * t
+}
+
+pub fn back_out(t: f32) -> f32 {
+    let s = t - 1.0;
+    1.0 + (BACK + 1.0) * s * s * s + BACK * s * s
+}
+
+pub fn back_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        let s = 2.0 * t;
+        s * s * ((BACK_IN_OUT + 1.0) * s - BACK_IN_OUT) / 2.0
+    } else {
+        let s = 2.0 * t - 2.0;
+        (s * s * ((BACK_IN_OUT + 1.0) * s + BACK_IN_OUT) + 2.0) / 2.0
+    }
+}
+
+pub fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        -(2f32.powf(10.0 * t - 10.0)) * ((10.0 * t - 10.75) * ELASTIC).sin()
+    }
+}
+
+pub fn elastic_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        2f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC).sin() + 1.0
+    }
+}
+
+pub fn elastic_in_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        -(2f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin()) / 2.0
+    } else {
+        2f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin() / 2.0 + 1.0
+    }
+}
+
+pub fn bounce_in(t: f32) -> f32 {
+    1.0 - bounce_out(1.0 - t)
+}
+
+pub fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+pub fn bounce_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+    } else {
+        (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+    }
+}
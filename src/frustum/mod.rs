@@ -0,0 +1,374 @@
+use crate::{
+    aabb::Aabb3,
+    mat::Mat4,
+    plane::Plane,
+    sphere::Sphere,
+    vec::{Vec3, Vec4},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CascadeSplit {
+    Uniform,
+    Logarithmic,
+    /// Blend between logarithmic (`1.0`) and uniform (`0.0`) splits.
+    Practical(f32),
+}
+
+/// Planes are normalized and face inwards, ordered
+/// left, right, bottom, top, near, far. An infinite projection has its far
+/// plane at infinity, with a zero normal and every point inside.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Frustum {
+    planes: [Plane; 6],
+}
+
+impl Frustum {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    pub fn from_planes(planes: [Plane; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Gribb–Hartmann extraction for a `[0, 1]` clip-space depth range.
+    pub fn from_matrix(view_projection: &Mat4) -> Self {
+        let row_2 = view_projection.row(2);
+        Self::from_rows(view_projection, row_2)
+    }
+
+    /// Gribb–Hartmann extraction for a `[-1, 1]` clip-space depth range.
+    pub fn from_matrix_gl(view_projection: &Mat4) -> Self {
+        let row_2 = view_projection.row(2) + view_projection.row(3);
+        Self::from_rows(view_projection, row_2)
+    }
+
+    fn from_rows(view_projection: &Mat4, near: Vec4) -> Self {
+        let row_0 = view_projection.row(0);
+        let row_1 = view_projection.row(1);
+        let row_2 = view_projection.row(2);
+        let row_3 = view_projection.row(3);
+
+        Self::from_planes([
+            Plane::from(row_3 + row_0).normalized(),
+            Plane::from(row_3 - row_0).normalized(),
+            Plane::from(row_3 + row_1).normalized(),
+            Plane::from(row_3 - row_1).normalized(),
+            Plane::from(near).normalized(),
+            Plane::from(row_3 - row_2).normalized(),
+        ])
+    }
+
+    pub fn planes(&self) -> &[Plane; 6] {
+        &self.planes
+    }
+
+    pub fn plane(&self, idx: usize) -> Plane {
+        self.planes[idx]
+    }
+
+    /// Near corners followed by far corners, each in the order
+    /// left-bottom, right-bottom, right-top, left-top. Returns `None` if the
+    /// planes do not meet, as with an infinite far plane.
+    pub fn corners(&self) -> Option<[Vec3; 8]> {
+        let corner = |depth: usize, x: usize, y: usize| {
+            Plane::intersect_planes(&self.planes[depth], &self.planes[x], &self.planes[y])
+        };
+
+        let mut corners = [Vec3::zero(); 8];
+        for (i, depth) in [Self::NEAR, Self::FAR].into_iter().enumerate() {
+            corners[i * 4] = corner(depth, Self::LEFT, Self::BOTTOM)?;
+            corners[i * 4 + 1] = corner(depth, Self::RIGHT, Self::BOTTOM)?;
+            corners[i * 4 + 2] = corner(depth, Self::RIGHT, Self::TOP)?;
+            corners[i * 4 + 3] = corner(depth, Self::LEFT, Self::TOP)?;
+        }
+        Some(corners)
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    pub fn test_sphere(&self, sphere: &Sphere) -> Containment {
+        let (center, radius) = (sphere.center(), sphere.radius());
+        let mut containment = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(center);
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance < radius {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    pub fn test_aabb(&self, aabb: &Aabb3) -> Containment {
+        let mut containment = Containment::Inside;
+        for plane in &self.planes {
+            let normal = plane.normal();
+            let select = |axis: usize, towards: bool| {
+                if (normal.idx(axis) >= 0.0) == towards {
+                    aabb.max().idx(axis)
+                } else {
+                    aabb.min().idx(axis)
+                }
+            };
+            let positive = Vec3::new(select(0, true), select(1, true), select(2, true));
+            let negative = Vec3::new(select(0, false), select(1, false), select(2, false));
+
+            if plane.signed_distance(positive) < 0.0 {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(negative) < 0.0 {
+                containment = Containment::Intersecting;
+            }
+        }
+        containment
+    }
+
+    /// View-space depths of the `count + 1` cascade boundaries between `near`
+    /// and `far`. A `count` of 0 is treated as a single cascade.
+    pub fn cascade_splits(near: f32, far: f32, count: usize, scheme: CascadeSplit) -> Vec<f32> {
+        let count = count.max(1);
+        (0..=count)
+            .map(|i| {
+                let fraction = i as f32 / count as f32;
+                let uniform = near + (far - near) * fraction;
+                let logarithmic = near * (far / near).powf(fraction);
+                match scheme {
+                    CascadeSplit::Uniform => uniform,
+                    CascadeSplit::Logarithmic => logarithmic,
+                    CascadeSplit::Practical(lambda) => {
+                        lambda * logarithmic + (1.0 - lambda) * uniform
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Corners of each cascade, in the same layout as [`Frustum::corners`].
+    /// `near` and `far` are the view-space depths of this frustum's planes.
+    /// Returns `None` if the frustum has no corners.
+    pub fn cascades(
+        &self,
+        near: f32,
+        far: f32,
+        count: usize,
+        scheme: CascadeSplit,
+    ) -> Option<Vec<[Vec3; 8]>> {
+        let corners = self.corners()?;
+        let slice = |depth: f32| {
+            let t = (depth - near) / (far - near);
+            let mut slice = [Vec3::zero(); 4];
+            for (i, corner) in slice.iter_mut().enumerate() {
+                *corner = corners[i] + (corners[i + 4] - corners[i]) * t;
+            }
+            slice
+        };
+
+        let cascades = Self::cascade_splits(near, far, count, scheme)
+            .windows(2)
+            .map(|split| {
+                let (near, far) = (slice(split[0]), slice(split[1]));
+                [
+                    near[0], near[1], near[2], near[3], far[0], far[1], far[2], far[3],
+                ]
+            })
+            .collect();
+        Some(cascades)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEAR: f32 = 1.0;
+    const FAR: f32 = 10.0;
+
+    /// Right-handed, 90° vertical field of view, square aspect, `[0, 1]` depth.
+    #[rustfmt::skip]
+    fn perspective() -> Mat4 {
+        Mat4::from_data(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, FAR / (NEAR - FAR), NEAR * FAR / (NEAR - FAR),
+            0.0, 0.0, -1.0, 0.0,
+        )
+    }
+
+    /// As `perspective`, but with `[-1, 1]` depth.
+    #[rustfmt::skip]
+    fn perspective_gl() -> Mat4 {
+        Mat4::from_data(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, (FAR + NEAR) / (NEAR - FAR), 2.0 * NEAR * FAR / (NEAR - FAR),
+            0.0, 0.0, -1.0, 0.0,
+        )
+    }
+
+    /// `perspective` with the far plane at infinity.
+    #[rustfmt::skip]
+    fn perspective_infinite() -> Mat4 {
+        Mat4::from_data(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, -1.0, -NEAR,
+            0.0, 0.0, -1.0, 0.0,
+        )
+    }
+
+    /// Right-handed box with x in `[-2, 2]`, y in `[-1, 1]` and `[0, 1]` depth.
+    #[rustfmt::skip]
+    fn orthographic() -> Mat4 {
+        Mat4::from_data(
+            0.5, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0 / (NEAR - FAR), NEAR / (NEAR - FAR),
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn assert_corners(frustum: &Frustum, expected: [(f32, f32, f32); 8]) {
+        let corners = frustum.corners().unwrap();
+        for (corner, (x, y, z)) in corners.iter().zip(expected) {
+            assert_vec(*corner, Vec3::new(x, y, z));
+        }
+    }
+
+    const PERSPECTIVE_CORNERS: [(f32, f32, f32); 8] = [
+        (-1.0, -1.0, -1.0),
+        (1.0, -1.0, -1.0),
+        (1.0, 1.0, -1.0),
+        (-1.0, 1.0, -1.0),
+        (-10.0, -10.0, -10.0),
+        (10.0, -10.0, -10.0),
+        (10.0, 10.0, -10.0),
+        (-10.0, 10.0, -10.0),
+    ];
+
+    #[test]
+    fn perspective_planes() {
+        let frustum = Frustum::from_matrix(&perspective());
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        let left = frustum.plane(Frustum::LEFT);
+        assert_vec(left.normal(), Vec3::new(half, 0.0, -half));
+        assert!(left.distance().abs() < 1e-5);
+
+        let near = frustum.plane(Frustum::NEAR);
+        assert_vec(near.normal(), Vec3::new(0.0, 0.0, -1.0));
+        assert!((near.distance() + NEAR).abs() < 1e-5);
+        let far = frustum.plane(Frustum::FAR);
+        assert_vec(far.normal(), Vec3::new(0.0, 0.0, 1.0));
+        assert!((far.distance() - FAR).abs() < 1e-4);
+
+        assert_corners(&frustum, PERSPECTIVE_CORNERS);
+        assert_corners(
+            &Frustum::from_matrix_gl(&perspective_gl()),
+            PERSPECTIVE_CORNERS,
+        );
+    }
+
+    #[test]
+    fn orthographic_planes() {
+        let frustum = Frustum::from_matrix(&orthographic());
+        assert_corners(
+            &frustum,
+            [
+                (-2.0, -1.0, -1.0),
+                (2.0, -1.0, -1.0),
+                (2.0, 1.0, -1.0),
+                (-2.0, 1.0, -1.0),
+                (-2.0, -1.0, -10.0),
+                (2.0, -1.0, -10.0),
+                (2.0, 1.0, -10.0),
+                (-2.0, 1.0, -10.0),
+            ],
+        );
+        assert!(frustum.contains_point(Vec3::new(1.5, 0.5, -5.0)));
+        assert!(!frustum.contains_point(Vec3::new(2.5, 0.5, -5.0)));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -11.0)));
+    }
+
+    #[test]
+    fn culling() {
+        let frustum = Frustum::from_matrix(&perspective());
+        let inside = Sphere::new(Vec3::new(0.0, 0.0, -5.0), 1.0);
+        let crossing = Sphere::new(Vec3::new(0.0, 0.0, -10.0), 1.0);
+        let behind = Sphere::new(Vec3::new(0.0, 0.0, 5.0), 1.0);
+        assert_eq!(frustum.test_sphere(&inside), Containment::Inside);
+        assert_eq!(frustum.test_sphere(&crossing), Containment::Intersecting);
+        assert_eq!(frustum.test_sphere(&behind), Containment::Outside);
+
+        let aabb = |center: Vec3| Aabb3::from_center_extents(center, Vec3::new(0.5, 0.5, 0.5));
+        assert_eq!(
+            frustum.test_aabb(&aabb(Vec3::new(0.0, 0.0, -5.0))),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.test_aabb(&aabb(Vec3::new(5.0, 0.0, -5.0))),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.test_aabb(&aabb(Vec3::new(8.0, 0.0, -5.0))),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn infinite_far_plane() {
+        let frustum = Frustum::from_matrix(&perspective_infinite());
+        for plane in &frustum.planes()[..Frustum::FAR] {
+            assert!(plane.distance().is_finite());
+        }
+        let far = frustum.plane(Frustum::FAR);
+        assert_eq!(far.normal(), Vec3::zero());
+        assert_eq!(far.distance(), f32::INFINITY);
+
+        assert!(frustum.contains_point(Vec3::new(0.0, 0.0, -1.0e6)));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -0.5)));
+        let distant = Sphere::new(Vec3::new(0.0, 0.0, -1.0e4), 10.0);
+        assert_eq!(frustum.test_sphere(&distant), Containment::Inside);
+
+        assert_eq!(frustum.corners(), None);
+        assert_eq!(frustum.cascades(NEAR, FAR, 4, CascadeSplit::Uniform), None);
+    }
+
+    #[test]
+    fn cascades_cover_frustum() {
+        let frustum = Frustum::from_matrix(&perspective());
+        let cascades = frustum
+            .cascades(NEAR, FAR, 3, CascadeSplit::Practical(0.5))
+            .unwrap();
+        assert_eq!(cascades.len(), 3);
+
+        let corners = frustum.corners().unwrap();
+        assert_eq!(cascades[0][..4], corners[..4]);
+        assert_eq!(cascades[2][4..], corners[4..]);
+        for pair in cascades.windows(2) {
+            assert_eq!(pair[0][4..], pair[1][..4]);
+        }
+    }
+}
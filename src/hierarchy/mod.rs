@@ -0,0 +1,306 @@
+use crate::{mat::Mat4, transform::Transform};
+
+/// A node's transform relative to its parent.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LocalTransform {
+    Trs(Transform),
+    Matrix(Mat4),
+}
+
+impl LocalTransform {
+    pub fn to_mat4(&self) -> Mat4 {
+        match self {
+            LocalTransform::Trs(transform) => transform.to_mat4(),
+            LocalTransform::Matrix(mat) => *mat,
+        }
+    }
+}
+
+impl Default for LocalTransform {
+    fn default() -> Self {
+        LocalTransform::Trs(Transform::identity())
+    }
+}
+
+impl From<Transform> for LocalTransform {
+    fn from(transform: Transform) -> Self {
+        LocalTransform::Trs(transform)
+    }
+}
+
+impl From<Mat4> for LocalTransform {
+    fn from(mat: Mat4) -> Self {
+        LocalTransform::Matrix(mat)
+    }
+}
+
+/// Nodes addressed by index, each with an optional parent and a local
+/// transform. `update` recomputes world matrices parents-first, touching
+/// only the subtrees whose local transforms or parents changed since.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Hierarchy {
+    parents: Vec<Option<usize>>,
+    locals: Vec<LocalTransform>,
+    worlds: Vec<Mat4>,
+    dirty: Vec<bool>,
+    /// Node indices with every parent before its children.
+    order: Vec<usize>,
+    order_dirty: bool,
+}
+
+impl Hierarchy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Adds a node and returns its index. Panics if `parent` does not exist.
+    pub fn add(&mut self, local: impl Into<LocalTransform>, parent: Option<usize>) -> usize {
+        if let Some(parent) = parent {
+            assert!(parent < self.len(), "parent {parent} does not exist");
+        }
+
+        let idx = self.len();
+        self.parents.push(parent);
+        self.locals.push(local.into());
+        self.worlds.push(Mat4::identity());
+        self.dirty.push(true);
+        // A new node comes after its parent, so it can go on the end.
+        self.order.push(idx);
+        idx
+    }
+
+    pub fn parent(&self, idx: usize) -> Option<usize> {
+        self.parents[idx]
+    }
+
+    pub fn children(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(move |(_, parent)| **parent == Some(idx))
+            .map(|(child, _)| child)
+    }
+
+    pub fn local(&self, idx: usize) -> &LocalTransform {
+        &self.locals[idx]
+    }
+
+    pub fn set_local(&mut self, idx: usize, local: impl Into<LocalTransform>) {
+        self.locals[idx] = local.into();
+        self.dirty[idx] = true;
+    }
+
+    /// World matrix as of the last `update`.
+    pub fn world(&self, idx: usize) -> Mat4 {
+        self.worlds[idx]
+    }
+
+    /// World matrices, indexed by node, as of the last `update`.
+    pub fn worlds(&self) -> &[Mat4] {
+        &self.worlds
+    }
+
+    /// Whether `ancestor` is `idx` or one of its ancestors.
+    pub fn is_ancestor(&self, ancestor: usize, idx: usize) -> bool {
+        let mut current = Some(idx);
+        while let Some(node) = current {
+            if node == ancestor {
+                return true;
+            }
+            current = self.parents[node];
+        }
+        false
+    }
+
+    /// Moves `idx` under `parent`, adjusting its local transform so its world
+    /// transform stays the same. TRS locals lose any shear this would need.
+    /// Returns `false`, changing nothing, if `parent` is `idx` or one of its
+    /// descendants, or if its world matrix is singular and cannot be undone.
+    pub fn reparent(&mut self, idx: usize, parent: Option<usize>) -> bool {
+        if let Some(parent) = parent {
+            if self.is_ancestor(idx, parent) {
+                return false;
+            }
+        }
+        self.update();
+
+        let parent_world = parent.map_or(Mat4::identity(), |parent| self.worlds[parent]);
+        if parent_world.determinant() == 0.0 {
+            return false;
+        }
+        let local = parent_world.inverse() * self.worlds[idx];
+        self.locals[idx] = match self.locals[idx] {
+            LocalTransform::Trs(_) => LocalTransform::Trs(Transform::from_mat4(&local)),
+            LocalTransform::Matrix(_) => LocalTransform::Matrix(local),
+        };
+
+        self.parents[idx] = parent;
+        self.dirty[idx] = true;
+        self.order_dirty = true;
+        true
+    }
+
+    /// Recomputes world matrices for changed nodes and their descendants.
+    /// Returns how many were recomputed.
+    pub fn update(&mut self) -> usize {
+        if self.order_dirty {
+            self.rebuild_order();
+        }
+
+        let mut recomputed = 0;
+        for &idx in &self.order {
+            let parent = self.parents[idx];
+            if let Some(parent) = parent {
+                // Parents come first in `order`, so their flag is final.
+                self.dirty[idx] |= self.dirty[parent];
+            }
+            if !self.dirty[idx] {
+                continue;
+            }
+
+            let local = self.locals[idx].to_mat4();
+            self.worlds[idx] = match parent {
+                Some(parent) => self.worlds[parent] * local,
+                None => local,
+            };
+            recomputed += 1;
+        }
+
+        self.dirty.iter_mut().for_each(|dirty| *dirty = false);
+        recomputed
+    }
+
+    fn rebuild_order(&mut self) {
+        let mut children = vec![Vec::new(); self.len()];
+        let mut stack = Vec::new();
+        for (idx, parent) in self.parents.iter().enumerate() {
+            match parent {
+                Some(parent) => children[*parent].push(idx),
+                None => stack.push(idx),
+            }
+        }
+
+        self.order.clear();
+        stack.reverse();
+        while let Some(idx) = stack.pop() {
+            self.order.push(idx);
+            stack.extend(children[idx].iter().rev());
+        }
+        self.order_dirty = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{quat::Quat, vec::Vec3};
+
+    fn assert_mat(actual: Mat4, expected: Mat4) {
+        for col in 0..4 {
+            assert!(
+                (actual.col(col) - expected.col(col)).magnitude() < 1e-4,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    fn trs(translation: Vec3, angle: f32, scale: f32) -> Transform {
+        Transform::new(
+            translation,
+            Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), angle),
+            Vec3::new(scale, scale, scale),
+        )
+    }
+
+    /// root ─ a ─ b ─ c, plus a second root `d` with child `e`.
+    fn sample() -> (Hierarchy, [usize; 6]) {
+        let mut hierarchy = Hierarchy::new();
+        let root = hierarchy.add(trs(Vec3::new(1.0, 0.0, 0.0), 0.3, 1.0), None);
+        let a = hierarchy.add(trs(Vec3::new(0.0, 2.0, 0.0), -0.7, 2.0), Some(root));
+        let b = hierarchy.add(trs(Vec3::new(0.5, 0.0, 1.0), 1.2, 0.5), Some(a));
+        let c = hierarchy.add(
+            Mat4::translate(Vec3::new(0.0, 0.0, -3.0)) * Mat4::rotate_x(0.4),
+            Some(b),
+        );
+        let d = hierarchy.add(trs(Vec3::new(-4.0, 1.0, 2.0), 2.0, 3.0), None);
+        let e = hierarchy.add(trs(Vec3::new(1.0, 1.0, 1.0), -0.2, 1.0), Some(d));
+        (hierarchy, [root, a, b, c, d, e])
+    }
+
+    #[test]
+    fn update_composes_parents_first() {
+        let (mut hierarchy, [root, a, b, c, _, _]) = sample();
+        assert_eq!(hierarchy.update(), 6);
+
+        let expected = hierarchy.local(root).to_mat4()
+            * hierarchy.local(a).to_mat4()
+            * hierarchy.local(b).to_mat4()
+            * hierarchy.local(c).to_mat4();
+        assert_mat(hierarchy.world(c), expected);
+    }
+
+    #[test]
+    fn update_recomputes_only_changed_subtree() {
+        let (mut hierarchy, [root, a, b, c, d, e]) = sample();
+        hierarchy.update();
+        assert_eq!(hierarchy.update(), 0);
+
+        let before = hierarchy.worlds().to_vec();
+        hierarchy.set_local(b, trs(Vec3::new(0.0, 1.0, 0.0), 0.0, 1.0));
+        assert_eq!(hierarchy.update(), 2);
+        for idx in [root, a, d, e] {
+            assert_eq!(hierarchy.world(idx), before[idx]);
+        }
+        assert_mat(
+            hierarchy.world(c),
+            hierarchy.world(b) * hierarchy.local(c).to_mat4(),
+        );
+
+        hierarchy.set_local(e, Transform::identity());
+        hierarchy.set_local(c, Transform::identity());
+        assert_eq!(hierarchy.update(), 2);
+        assert_mat(hierarchy.world(e), hierarchy.world(d));
+    }
+
+    #[test]
+    fn reparent_keeps_world_matrices() {
+        let (mut hierarchy, [root, a, b, c, d, e]) = sample();
+        hierarchy.update();
+        let before = hierarchy.worlds().to_vec();
+
+        // Move the subtree under `a` to the other root.
+        assert!(hierarchy.reparent(b, Some(e)));
+        assert_eq!(hierarchy.parent(b), Some(e));
+        assert_eq!(hierarchy.update(), 2);
+        for idx in [root, a, b, c, d, e] {
+            assert_mat(hierarchy.world(idx), before[idx]);
+        }
+
+        assert!(hierarchy.reparent(c, None));
+        hierarchy.update();
+        assert_mat(hierarchy.world(c), before[c]);
+        assert_eq!(hierarchy.children(b).count(), 0);
+    }
+
+    #[test]
+    fn reparent_rejects_cycles_and_singular_parents() {
+        let (mut hierarchy, [root, a, _, c, d, _]) = sample();
+        assert!(!hierarchy.reparent(a, Some(c)));
+        assert!(!hierarchy.reparent(a, Some(a)));
+        assert_eq!(hierarchy.parent(a), Some(root));
+
+        let flat = hierarchy.add(Mat4::scale(Vec3::new(1.0, 0.0, 1.0)), None);
+        assert!(!hierarchy.reparent(d, Some(flat)));
+        assert_eq!(hierarchy.parent(d), None);
+        hierarchy.update();
+        assert!(hierarchy.world(d).col(0).magnitude().is_finite());
+    }
+}
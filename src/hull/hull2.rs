@@ -0,0 +1,74 @@
+use crate::{polygon::Polygon2, vec::Vec2};
+
+/// Andrew's monotone chain. The hull is counter-clockwise and skips
+/// collinear points.
+pub fn convex_hull2(points: &[Vec2]) -> Polygon2 {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return Polygon2::new(sorted);
+    }
+
+    let turns_left = |hull: &[Vec2], point: Vec2| {
+        let a = hull[hull.len() - 2];
+        let b = hull[hull.len() - 1];
+        (b - a).cross(&(point - a)) > 0.0
+    };
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(sorted.len() + 1);
+    for point in &sorted {
+        while hull.len() >= 2 && !turns_left(&hull, *point) {
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+
+    let lower_len = hull.len() + 1;
+    for point in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, *point) {
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+    hull.pop();
+
+    Polygon2::new(hull)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_with_interior_and_collinear_points() {
+        let points = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(0.5, 1.5),
+            Vec2::new(0.0, 0.0),
+        ];
+        let hull = convex_hull2(&points);
+        assert_eq!(
+            hull.points(),
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(2.0, 2.0),
+                Vec2::new(0.0, 2.0),
+            ]
+        );
+        assert_eq!(hull.signed_area(), 4.0);
+        assert_eq!(Polygon2::convex_hull(&points), hull);
+    }
+
+    #[test]
+    fn fewer_than_three_distinct_points() {
+        let points = [Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0)];
+        assert_eq!(convex_hull2(&points).points(), [Vec2::new(1.0, 1.0)]);
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{aabb::Aabb3, plane::Plane, vec::Vec3};
+
+struct Face {
+    vertices: [usize; 3],
+    plane: Plane,
+    /// `neighbors[k]` is the face across `edges()[k]`.
+    neighbors: [usize; 3],
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl Face {
+    fn new(points: &[Vec3], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        Self {
+            vertices,
+            plane: Plane::from_points(a, b, c),
+            neighbors: [usize::MAX; 3],
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn edges(&self) -> [[usize; 2]; 3] {
+        let [a, b, c] = self.vertices;
+        [[a, b], [b, c], [c, a]]
+    }
+
+    fn edge_index(&self, edge: [usize; 2]) -> Option<usize> {
+        self.edges().iter().position(|e| *e == edge)
+    }
+}
+
+/// A closed triangle mesh whose faces wind counter-clockwise when seen from
+/// outside, so face normals point outwards.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConvexHull3 {
+    vertices: Vec<Vec3>,
+    faces: Vec<[usize; 3]>,
+}
+
+impl ConvexHull3 {
+    /// Quickhull. Returns `None` when the points are all coplanar.
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let bounds = Aabb3::from_points(points)?;
+        // Relative to the coordinate magnitude, so that rounding in the face
+        // planes stays within it at any scale and any distance from the origin.
+        let epsilon = (0..3)
+            .map(|axis| {
+                bounds
+                    .min()
+                    .idx(axis)
+                    .abs()
+                    .max(bounds.max().idx(axis).abs())
+            })
+            .sum::<f32>()
+            * 1e-5;
+
+        let initial = Self::initial_tetrahedron(points, epsilon)?;
+        let interior = initial.iter().fold(Vec3::zero(), |sum, i| sum + points[*i]) * 0.25;
+
+        let [a, b, c, d] = initial;
+        let mut faces: Vec<Face> = [[a, b, c], [a, d, b], [a, c, d], [b, d, c]]
+            .into_iter()
+            .map(|vertices| Self::oriented_face(points, vertices, interior))
+            .collect();
+        for i in 0..faces.len() {
+            for (k, [start, end]) in faces[i].edges().into_iter().enumerate() {
+                faces[i].neighbors[k] = (0..faces.len())
+                    .find(|j| faces[*j].edge_index([end, start]).is_some())
+                    .unwrap_or(i);
+            }
+        }
+
+        let unassigned: Vec<usize> = (0..points.len()).filter(|i| !initial.contains(i)).collect();
+        Self::assign(points, &mut faces, 0, &unassigned, epsilon);
+
+        while let Some(current) = faces
+            .iter()
+            .position(|face| face.alive && !face.outside.is_empty())
+        {
+            let plane = faces[current].plane;
+            let eye = faces[current]
+                .outside
+                .iter()
+                .copied()
+                .max_by(|i, j| {
+                    plane
+                        .signed_distance(points[*i])
+                        .total_cmp(&plane.signed_distance(points[*j]))
+                })
+                .unwrap_or_default();
+
+            // Walk the faces visible from `eye` across shared edges, starting
+            // at `current`. Visiting each face's edges counter-clockwise from
+            // the one we entered by yields the horizon as an ordered loop.
+            faces[current].alive = false;
+            let mut orphans = std::mem::take(&mut faces[current].outside);
+            let mut horizon: Vec<([usize; 2], usize)> = Vec::new();
+            let mut stack = vec![(current, 0, 0)];
+            while let Some(top) = stack.last_mut() {
+                let (face, first, step) = *top;
+                if step == 3 {
+                    stack.pop();
+                    continue;
+                }
+                top.2 += 1;
+
+                let edge = (first + step) % 3;
+                let neighbor = faces[face].neighbors[edge];
+                if !faces[neighbor].alive {
+                    continue;
+                }
+                if faces[neighbor].plane.signed_distance(points[eye]) > 0.0 {
+                    faces[neighbor].alive = false;
+                    orphans.append(&mut faces[neighbor].outside);
+                    let [start, end] = faces[face].edges()[edge];
+                    let back = faces[neighbor].edge_index([end, start]).unwrap_or(0);
+                    stack.push((neighbor, back, 0));
+                } else {
+                    horizon.push((faces[face].edges()[edge], neighbor));
+                }
+            }
+
+            let first_new = faces.len();
+            let count = horizon.len();
+            debug_assert!((0..count).all(|i| horizon[i].0[1] == horizon[(i + 1) % count].0[0]));
+            for (i, ([start, end], neighbor)) in horizon.into_iter().enumerate() {
+                let mut face = Face::new(points, [start, end, eye]);
+                face.neighbors = [
+                    neighbor,
+                    first_new + (i + 1) % count,
+                    first_new + (i + count - 1) % count,
+                ];
+                if let Some(back) = faces[neighbor].edge_index([end, start]) {
+                    faces[neighbor].neighbors[back] = first_new + i;
+                }
+                faces.push(face);
+            }
+
+            orphans.retain(|i| *i != eye);
+            Self::assign(points, &mut faces, first_new, &orphans, epsilon);
+        }
+
+        let mut remap = vec![usize::MAX; points.len()];
+        let mut vertices = Vec::new();
+        let mut hull_faces = Vec::new();
+        for face in faces.iter().filter(|face| face.alive) {
+            hull_faces.push(face.vertices.map(|i| {
+                if remap[i] == usize::MAX {
+                    remap[i] = vertices.len();
+                    vertices.push(points[i]);
+                }
+                remap[i]
+            }));
+        }
+
+        Some(Self {
+            vertices,
+            faces: hull_faces,
+        })
+    }
+
+    fn oriented_face(points: &[Vec3], vertices: [usize; 3], interior: Vec3) -> Face {
+        let face = Face::new(points, vertices);
+        if face.plane.signed_distance(interior) > 0.0 {
+            Face::new(points, [vertices[0], vertices[2], vertices[1]])
+        } else {
+            face
+        }
+    }
+
+    /// Gives each point to the first face from `first_face` onwards that it lies above.
+    fn assign(
+        points: &[Vec3],
+        faces: &mut [Face],
+        first_face: usize,
+        candidates: &[usize],
+        epsilon: f32,
+    ) {
+        for point in candidates {
+            if let Some(face) = faces[first_face..]
+                .iter_mut()
+                .find(|face| face.alive && face.plane.signed_distance(points[*point]) > epsilon)
+            {
+                face.outside.push(*point);
+            }
+        }
+    }
+
+    fn initial_tetrahedron(points: &[Vec3], epsilon: f32) -> Option<[usize; 4]> {
+        let furthest = |score: &dyn Fn(Vec3) -> f32| {
+            (0..points.len())
+                .max_by(|i, j| score(points[*i]).total_cmp(&score(points[*j])))
+                .unwrap_or(0)
+        };
+
+        let extremes = (0..3).flat_map(|axis| {
+            [
+                furthest(&|point: Vec3| point.idx(axis)),
+                furthest(&|point: Vec3| -point.idx(axis)),
+            ]
+        });
+        let extremes: Vec<usize> = extremes.collect();
+
+        let mut a = extremes[0];
+        let mut b = extremes[1];
+        for i in &extremes {
+            for j in &extremes {
+                if points[*i].distance(points[*j]) > points[a].distance(points[b]) {
+                    a = *i;
+                    b = *j;
+                }
+            }
+        }
+        if points[a].distance(points[b]) <= epsilon {
+            return None;
+        }
+
+        let line = points[b] - points[a];
+        let c = furthest(&|point: Vec3| line.cross(&(point - points[a])).magnitude_squared());
+        if line.cross(&(points[c] - points[a])).magnitude() <= epsilon * line.magnitude() {
+            return None;
+        }
+
+        let plane = Plane::from_points(points[a], points[b], points[c]);
+        let d = furthest(&|point: Vec3| plane.signed_distance(point).abs());
+        if plane.signed_distance(points[d]).abs() <= epsilon {
+            return None;
+        }
+
+        Some([a, b, c, d])
+    }
+
+    pub fn vertices(&self) -> &[Vec3] {
+        &self.vertices
+    }
+
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    pub fn face_normal(&self, face: usize) -> Vec3 {
+        let [a, b, c] = self.faces[face].map(|i| self.vertices[i]);
+        (b - a).cross(&(c - a)).normalized()
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        self.faces.iter().all(|face| {
+            let [a, b, c] = face.map(|i| self.vertices[i]);
+            Plane::from_points(a, b, c).signed_distance(point) <= 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, Sample, UnitSphere};
+
+    fn assert_closed_manifold(hull: &ConvexHull3) {
+        let mut edges: Vec<[usize; 2]> = hull
+            .faces()
+            .iter()
+            .flat_map(|[a, b, c]| [[*a, *b], [*b, *c], [*c, *a]])
+            .collect();
+        edges.sort_unstable();
+        assert!(
+            edges.windows(2).all(|pair| pair[0] != pair[1]),
+            "directed edge used twice"
+        );
+        for [a, b] in &edges {
+            assert!(edges.binary_search(&[*b, *a]).is_ok(), "open edge {a}-{b}");
+        }
+
+        // Euler characteristic of a sphere.
+        let vertices = hull.vertices().len() as isize;
+        let faces = hull.faces().len() as isize;
+        assert_eq!(vertices - (edges.len() / 2) as isize + faces, 2);
+    }
+
+    #[test]
+    fn cube_with_interior_points() {
+        let mut points: Vec<Vec3> = (0..8)
+            .map(|i| Vec3::new((i & 1) as f32, (i >> 1 & 1) as f32, (i >> 2 & 1) as f32))
+            .collect();
+        points.extend([Vec3::new(0.5, 0.5, 0.5), Vec3::new(0.2, 0.7, 0.4)]);
+
+        let hull = ConvexHull3::from_points(&points).unwrap();
+        assert_closed_manifold(&hull);
+        assert_eq!(hull.vertices().len(), 8);
+        assert_eq!(hull.faces().len(), 12);
+        assert!(hull.contains(Vec3::new(0.5, 0.5, 0.5)));
+        assert!(!hull.contains(Vec3::new(1.5, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn coplanar_points() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+        ];
+        assert_eq!(ConvexHull3::from_points(&points), None);
+    }
+
+    #[test]
+    fn random_sphere_points() {
+        for seed in 0..4 {
+            for radius in [1.0, 10.0, 100.0] {
+                let mut rng = Pcg32::new(seed);
+                let points: Vec<Vec3> = (0..1000)
+                    .map(|_| UnitSphere.sample(&mut rng) * radius)
+                    .collect();
+
+                let hull = ConvexHull3::from_points(&points).unwrap();
+                assert_closed_manifold(&hull);
+                for face in hull.faces() {
+                    let [a, b, c] = face.map(|i| hull.vertices()[i]);
+                    let plane = Plane::from_points(a, b, c);
+                    for point in &points {
+                        assert!(
+                            plane.signed_distance(*point) <= 1e-4 * radius,
+                            "seed {seed}, radius {radius}: point outside the hull by {}",
+                            plane.signed_distance(*point)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
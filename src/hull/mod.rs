@@ -0,0 +1,4 @@
+mod hull2;
+mod hull3;
+
+pub use self::{hull2::convex_hull2, hull3::ConvexHull3};
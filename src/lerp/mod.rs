@@ -0,0 +1,119 @@
+use crate::{
+    mat::Mat4,
+    point::{Point2D, Point3D},
+    quat::Quat,
+    rect::Rect2D,
+    size::Size2D,
+    vec::{Vec2, Vec3, Vec4},
+};
+
+/// Interpolation between two values, `t = 0` giving `self` and `t = 1`
+/// giving `rhs`. Values of `t` outside `[0, 1]` extrapolate where the type allows.
+pub trait Lerp {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        self + (rhs - self) * t
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        *self + (*rhs - *self) * t
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        *self + (*rhs - *self) * t
+    }
+}
+
+impl Lerp for Vec4 {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        *self + (*rhs - *self) * t
+    }
+}
+
+/// Spherical, along the shorter arc.
+impl Lerp for Quat {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        self.slerp(*rhs, t)
+    }
+}
+
+/// Decomposes both matrices, interpolating translation and scale linearly
+/// and rotation spherically. Shear is not preserved.
+impl Lerp for Mat4 {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        let (translation_a, rotation_a, scale_a) = self.decompose();
+        let (translation_b, rotation_b, scale_b) = rhs.decompose();
+        Mat4::from_trs(
+            translation_a.lerp(&translation_b, t),
+            rotation_a.lerp(&rotation_b, t),
+            scale_a.lerp(&scale_b, t),
+        )
+    }
+}
+
+impl Lerp for Point2D<f32> {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Point2D::new(self.x().lerp(&rhs.x(), t), self.y().lerp(&rhs.y(), t))
+    }
+}
+
+impl Lerp for Point3D<f32> {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Point3D::new(
+            self.x().lerp(&rhs.x(), t),
+            self.y().lerp(&rhs.y(), t),
+            self.z().lerp(&rhs.z(), t),
+        )
+    }
+}
+
+impl Lerp for Size2D<f32> {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Size2D::new(
+            self.width().lerp(&rhs.width(), t),
+            self.height().lerp(&rhs.height(), t),
+        )
+    }
+}
+
+impl Lerp for Rect2D<f32, f32> {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Rect2D::from_offset_and_size(
+            self.offset().lerp(&rhs.offset(), t),
+            self.size().lerp(&rhs.size(), t),
+        )
+    }
+}
+
+/// The `t` for which `a.lerp(&b, t) == value`. Returns 0 when `a == b`.
+pub fn inverse_lerp(a: f32, b: f32, value: f32) -> f32 {
+    if a == b {
+        0.0
+    } else {
+        (value - a) / (b - a)
+    }
+}
+
+/// Maps `value` from the range `from` onto the range `to`, without clamping.
+pub fn remap(value: f32, from: (f32, f32), to: (f32, f32)) -> f32 {
+    to.0.lerp(&to.1, inverse_lerp(from.0, from.1, value))
+}
+
+/// Hermite step from 0 at `edge_0` to 1 at `edge_1`, with zero slope at both edges.
+pub fn smoothstep(edge_0: f32, edge_1: f32, x: f32) -> f32 {
+    let t = inverse_lerp(edge_0, edge_1, x).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Perlin's variant of `smoothstep`, which also has zero second derivative at the edges.
+pub fn smootherstep(edge_0: f32, edge_1: f32, x: f32) -> f32 {
+    let t = inverse_lerp(edge_0, edge_1, x).clamp(0.0, 1.0);
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
@@ -1,8 +1,31 @@
+pub mod aabb;
+pub mod affine;
+pub mod anim;
+pub mod capsule;
+pub mod color;
+pub mod convex;
+pub mod curve;
+pub mod ease;
+pub mod frustum;
+pub mod hierarchy;
+pub mod hull;
+pub mod lerp;
 pub mod mat;
+pub mod noise;
+pub mod obb;
+pub mod plane;
 pub mod point;
+pub mod polygon;
 pub mod quad;
 pub mod quat;
 pub mod radians;
+pub mod random;
+pub mod ray;
 pub mod rect;
+pub mod segment;
 pub mod size;
+pub mod sphere;
+pub mod sweep;
+pub mod transform;
+pub mod triangle;
 pub mod vec;
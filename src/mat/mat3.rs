@@ -1,9 +1,279 @@
 use serde::Deserialize;
 
+use crate::{mat::Mat4, quat::Quat, vec::Vec3};
+
+/// Column-major
 #[repr(C)]
-// Column-major
-// TODO: Use Vec3
 #[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
 pub struct Mat3 {
-    n: [f32; 9],
+    data: [Vec3; 3],
+}
+
+impl Mat3 {
+    #[rustfmt::skip]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_data(
+        n00: f32, n01: f32, n02: f32,
+        n10: f32, n11: f32, n12: f32,
+        n20: f32, n21: f32, n22: f32,
+    ) -> Self {
+        Self {
+            data: [
+                Vec3::new(n00, n10, n20),
+                Vec3::new(n01, n11, n21),
+                Vec3::new(n02, n12, n22),
+            ],
+        }
+    }
+
+    pub fn from_cols(col_0: Vec3, col_1: Vec3, col_2: Vec3) -> Self {
+        Self {
+            data: [col_0, col_1, col_2],
+        }
+    }
+
+    #[rustfmt::skip]
+    pub fn identity() -> Self {
+        Self::from_data(
+            1.0, 0.0, 0.0,
+            0.0, 1.0, 0.0,
+            0.0, 0.0, 1.0,
+        )
+    }
+
+    #[rustfmt::skip]
+    pub fn rotation_from_quat(quat: Quat) -> Self {
+        let x2 = quat.x() * quat.x();
+        let y2 = quat.y() * quat.y();
+        let z2 = quat.z() * quat.z();
+
+        let xy = quat.x() * quat.y();
+        let xz = quat.x() * quat.z();
+        let yz = quat.y() * quat.z();
+        let wx = quat.w() * quat.x();
+        let wy = quat.w() * quat.y();
+        let wz = quat.w() * quat.z();
+
+        Self::from_data(
+            1.0 - 2.0 * (y2 + z2), 2.0 * (xy - wz), 2.0 * (xz + wy),
+            2.0 * (xy + wz), 1.0 - 2.0 * (x2 + z2), 2.0 * (yz - wx),
+            2.0 * (xz - wy), 2.0 * (yz + wx), 1.0 - 2.0 * (x2 + y2),
+        )
+    }
+
+    pub fn idx(&self, row: usize, col: usize) -> f32 {
+        self.data[col].idx(row)
+    }
+
+    pub fn row(&self, idx: usize) -> Vec3 {
+        Vec3::new(
+            self.data[0].idx(idx),
+            self.data[1].idx(idx),
+            self.data[2].idx(idx),
+        )
+    }
+
+    pub fn col(&self, idx: usize) -> Vec3 {
+        self.data[idx]
+    }
+
+    pub fn transposed(self) -> Self {
+        Self {
+            data: [self.row(0), self.row(1), self.row(2)],
+        }
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.data[0].dot(&self.data[1].cross(&self.data[2]))
+    }
+
+    pub fn inverse(self) -> Self {
+        let a = self.data[0];
+        let b = self.data[1];
+        let c = self.data[2];
+
+        let r0 = b.cross(&c);
+        let r1 = c.cross(&a);
+        let r2 = a.cross(&b);
+
+        let inv_det = 1.0 / r2.dot(&c);
+
+        Self {
+            data: [r0 * inv_det, r1 * inv_det, r2 * inv_det],
+        }
+        .transposed()
+    }
+
+    /// Eigen-decomposition of a symmetric matrix using cyclic Jacobi rotations.
+    /// Returns the eigenvalues and a rotation whose columns are the matching
+    /// unit eigenvectors.
+    pub fn symmetric_eigen(&self) -> (Vec3, Mat3) {
+        const MAX_SWEEPS: usize = 32;
+        const EPSILON: f32 = 1e-10;
+
+        let mut a = [[0.0; 3]; 3];
+        for (i, row) in a.iter_mut().enumerate() {
+            *row = self.row(i).into();
+        }
+        let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+            if off_diagonal < EPSILON {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q].abs() < EPSILON {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+
+        let eigenvalues = Vec3::new(a[0][0], a[1][1], a[2][2]);
+        let col_0 = Vec3::new(v[0][0], v[1][0], v[2][0]);
+        let col_1 = Vec3::new(v[0][1], v[1][1], v[2][1]);
+        // Keep the basis right-handed so it is a rotation rather than a reflection.
+        let eigenvectors = Mat3::from_cols(col_0, col_1, col_0.cross(&col_1));
+        (eigenvalues, eigenvectors)
+    }
+}
+
+impl std::ops::Mul<Mat3> for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        let row_0 = self.row(0);
+        let row_1 = self.row(1);
+        let row_2 = self.row(2);
+
+        Self {
+            data: [
+                Vec3::new(
+                    row_0.dot(&rhs.col(0)),
+                    row_1.dot(&rhs.col(0)),
+                    row_2.dot(&rhs.col(0)),
+                ),
+                Vec3::new(
+                    row_0.dot(&rhs.col(1)),
+                    row_1.dot(&rhs.col(1)),
+                    row_2.dot(&rhs.col(1)),
+                ),
+                Vec3::new(
+                    row_0.dot(&rhs.col(2)),
+                    row_1.dot(&rhs.col(2)),
+                    row_2.dot(&rhs.col(2)),
+                ),
+            ],
+        }
+    }
+}
+
+impl std::ops::Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        Vec3::new(
+            self.row(0).dot(&rhs),
+            self.row(1).dot(&rhs),
+            self.row(2).dot(&rhs),
+        )
+    }
+}
+
+impl From<[[f32; 3]; 3]> for Mat3 {
+    fn from(data: [[f32; 3]; 3]) -> Self {
+        Self {
+            data: [data[0].into(), data[1].into(), data[2].into()],
+        }
+    }
+}
+
+impl From<Mat3> for [[f32; 3]; 3] {
+    fn from(mat: Mat3) -> Self {
+        [mat.data[0].into(), mat.data[1].into(), mat.data[2].into()]
+    }
+}
+
+impl From<Mat4> for Mat3 {
+    fn from(mat: Mat4) -> Self {
+        Self {
+            data: [mat.col(0).xyz(), mat.col(1).xyz(), mat.col(2).xyz()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, RandomSource};
+
+    fn random_symmetric(rng: &mut Pcg32) -> Mat3 {
+        let mut value = || rng.range(-10.0, 10.0);
+        let (a, b, c, d, e, f) = (value(), value(), value(), value(), value(), value());
+        Mat3::from_data(a, b, c, b, d, e, c, e, f)
+    }
+
+    fn assert_eigen(mat: Mat3) {
+        let (values, vectors) = mat.symmetric_eigen();
+        let scale = (0..3)
+            .flat_map(|row| (0..3).map(move |col| (row, col)))
+            .map(|(row, col)| mat.idx(row, col).abs())
+            .fold(1.0, f32::max);
+
+        for i in 0..3 {
+            let vector = vectors.col(i);
+            let residual = mat * vector - vector * values.idx(i);
+            assert!(
+                residual.magnitude() < 1e-4 * scale,
+                "A·v != λ·v for {mat:?}: residual {residual:?}"
+            );
+            assert!((vector.magnitude() - 1.0).abs() < 1e-5);
+        }
+        assert!((vectors.determinant() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn symmetric_eigen_random_matrices() {
+        let mut rng = Pcg32::new(30);
+        for _ in 0..200 {
+            assert_eigen(random_symmetric(&mut rng));
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen_diagonal_and_repeated() {
+        assert_eigen(Mat3::identity());
+        assert_eigen(Mat3::from_data(
+            3.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 5.0,
+        ));
+        assert_eigen(Mat3::from_data(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0));
+
+        let (values, _) =
+            Mat3::from_data(2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0).symmetric_eigen();
+        let mut values: Vec<f32> = (0..3).map(|i| values.idx(i)).collect();
+        values.sort_by(f32::total_cmp);
+        for (value, expected) in values.iter().zip([1.0, 3.0, 3.0]) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+    }
 }
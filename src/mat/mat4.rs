@@ -1,7 +1,7 @@
 use std::fmt::Display;
-use serde::Deserialize;
 
 use crate::{
+    mat::Mat3,
     quat::Quat,
     vec::{Vec3, Vec4},
 };
@@ -15,6 +15,7 @@ pub struct Mat4 {
 
 impl Mat4 {
     #[rustfmt::skip]
+    #[allow(clippy::too_many_arguments)]
     pub fn from_data(
         n00: f32, n01: f32, n02: f32, n03: f32,
         n10: f32, n11: f32, n12: f32, n13: f32,
@@ -163,6 +164,104 @@ impl Mat4 {
             0.0, 0.0, 0.0,1.0,
         )
     }
+
+    /// Equivalent to `translate(translation) * rotation_from_quat(rotation) * scale(scale)`.
+    pub fn from_trs(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        let rotation = Mat3::rotation_from_quat(rotation);
+        let axis = |idx: usize| {
+            let axis = rotation.col(idx) * scale.idx(idx);
+            Vec4::new(axis.x(), axis.y(), axis.z(), 0.0)
+        };
+        Self {
+            data: [axis(0), axis(1), axis(2), Vec4::from(translation)],
+        }
+    }
+
+    /// Splits an affine matrix into translation, rotation and scale. Shear is
+    /// lost, and a reflection is folded into a negative x scale.
+    pub fn decompose(&self) -> (Vec3, Quat, Vec3) {
+        let translation = self.col(3).xyz();
+        let axes = [self.col(0).xyz(), self.col(1).xyz(), self.col(2).xyz()];
+        let mut scale = Vec3::new(
+            axes[0].magnitude(),
+            axes[1].magnitude(),
+            axes[2].magnitude(),
+        );
+        if Mat3::from_cols(axes[0], axes[1], axes[2]).determinant() < 0.0 {
+            scale = Vec3::new(-scale.x(), scale.y(), scale.z());
+        }
+
+        let axis = |idx: usize| {
+            let length = scale.idx(idx);
+            if length == 0.0 {
+                let mut unit = [0.0; 3];
+                unit[idx] = 1.0;
+                unit.into()
+            } else {
+                axes[idx] / length
+            }
+        };
+        let rotation = Quat::from_mat3(&Mat3::from_cols(axis(0), axis(1), axis(2)));
+
+        (translation, rotation, scale)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let a = self.col(0).xyz();
+        let b = self.col(1).xyz();
+        let c = self.col(2).xyz();
+        let d = self.col(3).xyz();
+
+        let row_3 = self.row(3);
+        let u = a * row_3.y() - b * row_3.x();
+        let v = c * row_3.w() - d * row_3.z();
+        a.cross(&b).dot(&v) + c.cross(&d).dot(&u)
+    }
+
+    #[rustfmt::skip]
+    pub fn inverse(self) -> Self {
+        let a = self.col(0).xyz();
+        let b = self.col(1).xyz();
+        let c = self.col(2).xyz();
+        let d = self.col(3).xyz();
+
+        let row_3 = self.row(3);
+        let x = row_3.x();
+        let y = row_3.y();
+        let z = row_3.z();
+        let w = row_3.w();
+
+        let s = a.cross(&b);
+        let t = c.cross(&d);
+        let u = a * y - b * x;
+        let v = c * w - d * z;
+
+        let inv_det = 1.0 / (s.dot(&v) + t.dot(&u));
+        let s = s * inv_det;
+        let t = t * inv_det;
+        let u = u * inv_det;
+        let v = v * inv_det;
+
+        let r0 = b.cross(&v) + t * y;
+        let r1 = v.cross(&a) - t * x;
+        let r2 = d.cross(&u) + s * w;
+        let r3 = u.cross(&c) - s * z;
+
+        Self::from_data(
+            r0.x(), r0.y(), r0.z(), -b.dot(&t),
+            r1.x(), r1.y(), r1.z(), a.dot(&t),
+            r2.x(), r2.y(), r2.z(), -d.dot(&s),
+            r3.x(), r3.y(), r3.z(), c.dot(&s),
+        )
+    }
+
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        (*self * Vec4::from(point)).xyz()
+    }
+
+    pub fn transform_vector(&self, vec: Vec3) -> Vec3 {
+        (*self * Vec4::new(vec.x(), vec.y(), vec.z(), 0.0)).xyz()
+    }
 }
 
 impl std::ops::Mul<Mat4> for Mat4 {
@@ -0,0 +1,171 @@
+use super::{Noise, NoiseDerivative};
+use crate::vec::{Vec2, Vec3};
+
+/// Octave settings shared by the fractal combinators. Each octave samples
+/// at `lacunarity` times the frequency and `gain` times the amplitude of
+/// the one before.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Octaves {
+    count: u32,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl Octaves {
+    /// Frequency and amplitude of each octave, with amplitudes summing to 1.
+    fn iter(self) -> impl Iterator<Item = (f32, f32)> {
+        let total: f32 = (0..self.count).map(|idx| self.gain.powi(idx as i32)).sum();
+        (0..self.count).map(move |idx| {
+            (
+                self.lacunarity.powi(idx as i32),
+                self.gain.powi(idx as i32) / total,
+            )
+        })
+    }
+}
+
+macro_rules! fractal {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name<N> {
+            noise: N,
+            octaves: Octaves,
+        }
+
+        impl<N> $name<N> {
+            /// Lacunarity 2 and gain 0.5.
+            pub fn new(noise: N, octaves: u32) -> Self {
+                Self::with_params(noise, octaves, 2.0, 0.5)
+            }
+
+            pub fn with_params(noise: N, octaves: u32, lacunarity: f32, gain: f32) -> Self {
+                Self {
+                    noise,
+                    octaves: Octaves {
+                        count: octaves.max(1),
+                        lacunarity,
+                        gain,
+                    },
+                }
+            }
+
+            pub fn noise(&self) -> &N {
+                &self.noise
+            }
+        }
+    };
+}
+
+fractal!(
+    /// Fractional Brownian motion: octaves of `N` summed, keeping its range.
+    Fbm
+);
+fractal!(
+    /// Inverted absolute octaves squared, giving sharp crests, in `[0, 1]`.
+    Ridged
+);
+fractal!(
+    /// Absolute octaves summed, giving billowy creases, in `[0, 1]`.
+    Turbulence
+);
+
+impl<P, N> Noise<P> for Fbm<N>
+where
+    P: Copy + std::ops::Mul<f32, Output = P>,
+    N: Noise<P>,
+{
+    fn sample(&self, point: P) -> f32 {
+        self.octaves
+            .iter()
+            .map(|(frequency, amplitude)| self.noise.sample(point * frequency) * amplitude)
+            .sum()
+    }
+}
+
+impl<P, N> NoiseDerivative<P> for Fbm<N>
+where
+    P: Copy + std::ops::Mul<f32, Output = P> + std::ops::Add<Output = P>,
+    N: NoiseDerivative<P>,
+{
+    fn sample_with_derivative(&self, point: P) -> (f32, P) {
+        let mut octaves = self.octaves.iter().map(|(frequency, amplitude)| {
+            let (value, gradient) = self.noise.sample_with_derivative(point * frequency);
+            (value * amplitude, gradient * (amplitude * frequency))
+        });
+        let first = octaves.next().expect("at least one octave");
+        octaves.fold(first, |(value, gradient), (octave, octave_gradient)| {
+            (value + octave, gradient + octave_gradient)
+        })
+    }
+}
+
+impl<P, N> Noise<P> for Ridged<N>
+where
+    P: Copy + std::ops::Mul<f32, Output = P>,
+    N: Noise<P>,
+{
+    fn sample(&self, point: P) -> f32 {
+        self.octaves
+            .iter()
+            .map(|(frequency, amplitude)| {
+                let ridge = 1.0 - self.noise.sample(point * frequency).abs();
+                ridge * ridge * amplitude
+            })
+            .sum()
+    }
+}
+
+impl<P, N> Noise<P> for Turbulence<N>
+where
+    P: Copy + std::ops::Mul<f32, Output = P>,
+    N: Noise<P>,
+{
+    fn sample(&self, point: P) -> f32 {
+        self.octaves
+            .iter()
+            .map(|(frequency, amplitude)| self.noise.sample(point * frequency).abs() * amplitude)
+            .sum()
+    }
+}
+
+/// Domain warping: samples `noise` at the point displaced by `warp`, which
+/// is sampled once per axis at decorrelated offsets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warp<N, W> {
+    noise: N,
+    warp: W,
+    strength: f32,
+}
+
+impl<N, W> Warp<N, W> {
+    pub fn new(noise: N, warp: W, strength: f32) -> Self {
+        Self {
+            noise,
+            warp,
+            strength,
+        }
+    }
+}
+
+/// Arbitrary offsets that keep the per-axis warp samples unrelated.
+const WARP_OFFSETS: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [5.2, 1.3, 7.1], [1.7, 9.2, 3.4]];
+
+impl<N: Noise<Vec2>, W: Noise<Vec2>> Noise<Vec2> for Warp<N, W> {
+    fn sample(&self, point: Vec2) -> f32 {
+        let offset = |idx: usize| {
+            let [x, y, _] = WARP_OFFSETS[idx];
+            self.warp.sample(point + Vec2::new(x, y))
+        };
+        let displacement = Vec2::new(offset(0), offset(1));
+        self.noise.sample(point + displacement * self.strength)
+    }
+}
+
+impl<N: Noise<Vec3>, W: Noise<Vec3>> Noise<Vec3> for Warp<N, W> {
+    fn sample(&self, point: Vec3) -> f32 {
+        let offset = |idx: usize| self.warp.sample(point + WARP_OFFSETS[idx].into());
+        let displacement = Vec3::new(offset(0), offset(1), offset(2));
+        self.noise.sample(point + displacement * self.strength)
+    }
+}
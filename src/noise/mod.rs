@@ -0,0 +1,262 @@
+mod fractal;
+mod perlin;
+mod simplex;
+mod value;
+mod worley;
+
+pub use self::{
+    fractal::{Fbm, Ridged, Turbulence, Warp},
+    perlin::Perlin,
+    simplex::Simplex,
+    value::Value,
+    worley::Worley,
+};
+
+use crate::vec::{Vec2, Vec3};
+use std::f32::consts::FRAC_1_SQRT_2;
+
+/// A deterministic scalar field over points of type `P`.
+pub trait Noise<P> {
+    fn sample(&self, point: P) -> f32;
+}
+
+/// Noise with an analytic gradient.
+pub trait NoiseDerivative<P>: Noise<P> {
+    /// Value and gradient at `point`.
+    fn sample_with_derivative(&self, point: P) -> (f32, P);
+}
+
+impl<P, N: Noise<P>> Noise<P> for &N {
+    fn sample(&self, point: P) -> f32 {
+        (*self).sample(point)
+    }
+}
+
+/// Ken Perlin's lattice hash: a seeded shuffle of 0..256, repeated so that
+/// nested lookups never need wrapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Permutation {
+    table: [u8; 512],
+}
+
+impl Permutation {
+    fn new(seed: u32) -> Self {
+        let mut values: [u8; 256] = std::array::from_fn(|idx| idx as u8);
+        let mut state = u64::from(seed);
+        for idx in (1..values.len()).rev() {
+            let swap = (splitmix64(&mut state) % (idx as u64 + 1)) as usize;
+            values.swap(idx, swap);
+        }
+
+        Self {
+            table: std::array::from_fn(|idx| values[idx & 255]),
+        }
+    }
+
+    fn hash(&self, coords: &[i32]) -> usize {
+        coords.iter().fold(0, |hash, coord| {
+            self.table[hash + (coord & 255) as usize] as usize
+        })
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Quintic fade curve and its derivative.
+fn fade(t: f32) -> (f32, f32) {
+    (
+        t * t * t * (t * (t * 6.0 - 15.0) + 10.0),
+        30.0 * t * t * (t * (t - 2.0) + 1.0),
+    )
+}
+
+fn gradient_2(hash: usize) -> Vec2 {
+    const GRADIENTS: [[f32; 2]; 8] = [
+        [1.0, 0.0],
+        [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+        [0.0, 1.0],
+        [-FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+        [-1.0, 0.0],
+        [-FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+        [0.0, -1.0],
+        [FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+    ];
+    GRADIENTS[hash & 7].into()
+}
+
+fn gradient_3(hash: usize) -> Vec3 {
+    const GRADIENTS: [[f32; 3]; 12] = [
+        [1.0, 1.0, 0.0],
+        [-1.0, 1.0, 0.0],
+        [1.0, -1.0, 0.0],
+        [-1.0, -1.0, 0.0],
+        [1.0, 0.0, 1.0],
+        [-1.0, 0.0, 1.0],
+        [1.0, 0.0, -1.0],
+        [-1.0, 0.0, -1.0],
+        [0.0, 1.0, 1.0],
+        [0.0, -1.0, 1.0],
+        [0.0, 1.0, -1.0],
+        [0.0, -1.0, -1.0],
+    ];
+    GRADIENTS[hash % 12].into()
+}
+
+/// Integer lattice cell containing `value`, and the offset within it.
+fn cell(value: f32) -> (i32, f32) {
+    let floor = value.floor();
+    (floor as i32, value - floor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, RandomSource};
+
+    fn points_2(seed: u64, count: usize) -> Vec<Vec2> {
+        let mut rng = Pcg32::new(seed);
+        (0..count)
+            .map(|_| Vec2::new(rng.range(-50.0, 50.0), rng.range(-50.0, 50.0)))
+            .collect()
+    }
+
+    fn points_3(seed: u64, count: usize) -> Vec<Vec3> {
+        let mut rng = Pcg32::new(seed);
+        (0..count)
+            .map(|_| {
+                Vec3::new(
+                    rng.range(-50.0, 50.0),
+                    rng.range(-50.0, 50.0),
+                    rng.range(-50.0, 50.0),
+                )
+            })
+            .collect()
+    }
+
+    fn assert_range<P: Copy>(noise: &impl Noise<P>, points: &[P], min: f32, max: f32) {
+        for point in points {
+            let value = noise.sample(*point);
+            assert!(
+                (min..=max).contains(&value),
+                "{value} outside [{min}, {max}]"
+            );
+        }
+    }
+
+    fn assert_deterministic<P: Copy, N: Noise<P>>(new: impl Fn(u32) -> N, points: &[P]) {
+        let (a, b, other) = (new(3), new(3), new(4));
+        assert!(points
+            .iter()
+            .all(|point| a.sample(*point) == b.sample(*point)));
+        assert!(points
+            .iter()
+            .any(|point| a.sample(*point) != other.sample(*point)));
+    }
+
+    /// Compares the analytic gradient against central differences.
+    fn assert_gradient_2(noise: &impl NoiseDerivative<Vec2>, points: &[Vec2]) {
+        const H: f32 = 1e-3;
+        for point in points {
+            let (value, gradient) = noise.sample_with_derivative(*point);
+            assert_eq!(value, noise.sample(*point));
+            for axis in 0..2 {
+                let mut step = [0.0; 2];
+                step[axis] = H;
+                let step = Vec2::from(step);
+                let numeric =
+                    (noise.sample(*point + step) - noise.sample(*point - step)) / (2.0 * H);
+                assert!(
+                    (numeric - gradient.idx(axis)).abs() < 0.02 * gradient.magnitude().max(1.0),
+                    "{point:?} axis {axis}: {numeric} != {}",
+                    gradient.idx(axis)
+                );
+            }
+        }
+    }
+
+    fn assert_gradient_3(noise: &impl NoiseDerivative<Vec3>, points: &[Vec3]) {
+        const H: f32 = 1e-3;
+        for point in points {
+            let (value, gradient) = noise.sample_with_derivative(*point);
+            assert_eq!(value, noise.sample(*point));
+            for axis in 0..3 {
+                let mut step = [0.0; 3];
+                step[axis] = H;
+                let step = Vec3::from(step);
+                let numeric =
+                    (noise.sample(*point + step) - noise.sample(*point - step)) / (2.0 * H);
+                assert!(
+                    (numeric - gradient.idx(axis)).abs() < 0.02 * gradient.magnitude().max(1.0),
+                    "{point:?} axis {axis}: {numeric} != {}",
+                    gradient.idx(axis)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic_per_seed() {
+        let (points_2, points_3) = (points_2(1, 64), points_3(1, 64));
+        assert_deterministic(Perlin::new, &points_2);
+        assert_deterministic(Perlin::new, &points_3);
+        assert_deterministic(Simplex::new, &points_2);
+        assert_deterministic(Simplex::new, &points_3);
+        assert_deterministic(Value::new, &points_2);
+        assert_deterministic(Value::new, &points_3);
+        assert_deterministic(Worley::new, &points_2);
+        assert_deterministic(Worley::new, &points_3);
+        assert_deterministic(|seed| Fbm::new(Perlin::new(seed), 4), &points_2);
+    }
+
+    #[test]
+    fn output_ranges() {
+        let (points_2, points_3) = (points_2(2, 4096), points_3(2, 4096));
+        for seed in 0..4 {
+            assert_range(&Perlin::new(seed), &points_2, -1.0, 1.0);
+            assert_range(&Perlin::new(seed), &points_3, -1.0, 1.0);
+            assert_range(&Simplex::new(seed), &points_2, -1.0, 1.0);
+            assert_range(&Simplex::new(seed), &points_3, -1.0, 1.0);
+            assert_range(&Value::new(seed), &points_2, -1.0, 1.0);
+            assert_range(&Value::new(seed), &points_3, -1.0, 1.0);
+            assert_range(&Worley::new(seed), &points_2, 0.0, 1.2);
+            assert_range(&Worley::new(seed), &points_3, 0.0, 1.2);
+            assert_range(&Fbm::new(Simplex::new(seed), 5), &points_2, -1.0, 1.0);
+            assert_range(&Ridged::new(Simplex::new(seed), 5), &points_2, 0.0, 1.0);
+            assert_range(&Turbulence::new(Perlin::new(seed), 5), &points_3, 0.0, 1.0);
+        }
+
+        // Perlin noise vanishes on the lattice.
+        let perlin = Perlin::new(9);
+        for point in [Vec3::new(0.0, 0.0, 0.0), Vec3::new(3.0, -7.0, 12.0)] {
+            assert_eq!(perlin.sample(point), 0.0);
+            assert_eq!(perlin.sample(Vec2::new(point.x(), point.y())), 0.0);
+        }
+
+        let worley = Worley::new(5);
+        for point in &points_2[..64] {
+            let (f1, f2) = worley.distances_2(*point);
+            assert!(f1 <= f2);
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        let (points_2, points_3) = (points_2(3, 256), points_3(3, 256));
+        for seed in 0..2 {
+            assert_gradient_2(&Perlin::new(seed), &points_2);
+            assert_gradient_3(&Perlin::new(seed), &points_3);
+            assert_gradient_2(&Simplex::new(seed), &points_2);
+            assert_gradient_3(&Simplex::new(seed), &points_3);
+            assert_gradient_2(&Value::new(seed), &points_2);
+            assert_gradient_3(&Value::new(seed), &points_3);
+            assert_gradient_2(&Fbm::new(Perlin::new(seed), 3), &points_2);
+            assert_gradient_3(&Fbm::new(Simplex::new(seed), 3), &points_3);
+        }
+    }
+}
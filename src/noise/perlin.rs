@@ -0,0 +1,112 @@
+use super::{cell, fade, gradient_2, gradient_3, Noise, NoiseDerivative, Permutation};
+use crate::vec::{Vec2, Vec3};
+use std::f32::consts::SQRT_2;
+
+/// Improved Perlin gradient noise, roughly in `[-1, 1]` and zero at every
+/// lattice point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perlin {
+    permutation: Permutation,
+}
+
+impl Perlin {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+}
+
+impl Noise<Vec2> for Perlin {
+    fn sample(&self, point: Vec2) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec2> for Perlin {
+    fn sample_with_derivative(&self, point: Vec2) -> (f32, Vec2) {
+        let (ix, fx) = cell(point.x);
+        let (iy, fy) = cell(point.y);
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+
+        let hash = |x: i32, y: i32| self.permutation.hash(&[ix + x, iy + y]);
+        let g00 = gradient_2(hash(0, 0));
+        let g10 = gradient_2(hash(1, 0));
+        let g01 = gradient_2(hash(0, 1));
+        let g11 = gradient_2(hash(1, 1));
+
+        let n00 = g00.dot(&Vec2::new(fx, fy));
+        let n10 = g10.dot(&Vec2::new(fx - 1.0, fy));
+        let n01 = g01.dot(&Vec2::new(fx, fy - 1.0));
+        let n11 = g11.dot(&Vec2::new(fx - 1.0, fy - 1.0));
+
+        let (k1, k2, k3) = (n10 - n00, n01 - n00, n00 - n10 - n01 + n11);
+        let value = n00 + k1 * u + k2 * v + k3 * u * v;
+        let gradient = g00
+            + (g10 - g00) * u
+            + (g01 - g00) * v
+            + (g00 - g10 - g01 + g11) * (u * v)
+            + Vec2::new(du * (k1 + k3 * v), dv * (k2 + k3 * u));
+
+        (value * SQRT_2, gradient * SQRT_2)
+    }
+}
+
+impl Noise<Vec3> for Perlin {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec3> for Perlin {
+    fn sample_with_derivative(&self, point: Vec3) -> (f32, Vec3) {
+        let (ix, fx) = cell(point.x());
+        let (iy, fy) = cell(point.y());
+        let (iz, fz) = cell(point.z());
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+        let (w, dw) = fade(fz);
+
+        let corner = |x: i32, y: i32, z: i32| {
+            let gradient = gradient_3(self.permutation.hash(&[ix + x, iy + y, iz + z]));
+            let offset = Vec3::new(fx - x as f32, fy - y as f32, fz - z as f32);
+            (gradient, gradient.dot(&offset))
+        };
+        let (g000, n000) = corner(0, 0, 0);
+        let (g100, n100) = corner(1, 0, 0);
+        let (g010, n010) = corner(0, 1, 0);
+        let (g110, n110) = corner(1, 1, 0);
+        let (g001, n001) = corner(0, 0, 1);
+        let (g101, n101) = corner(1, 0, 1);
+        let (g011, n011) = corner(0, 1, 1);
+        let (g111, n111) = corner(1, 1, 1);
+
+        // Trilinear interpolation expanded into a polynomial in u, v and w.
+        let k1 = n100 - n000;
+        let k2 = n010 - n000;
+        let k3 = n001 - n000;
+        let k4 = n000 - n100 - n010 + n110;
+        let k5 = n000 - n010 - n001 + n011;
+        let k6 = n000 - n100 - n001 + n101;
+        let k7 = -n000 + n100 + n010 - n110 + n001 - n101 - n011 + n111;
+        let value =
+            n000 + k1 * u + k2 * v + k3 * w + k4 * u * v + k5 * v * w + k6 * w * u + k7 * u * v * w;
+
+        let gradient = g000
+            + (g100 - g000) * u
+            + (g010 - g000) * v
+            + (g001 - g000) * w
+            + (g000 - g100 - g010 + g110) * (u * v)
+            + (g000 - g010 - g001 + g011) * (v * w)
+            + (g000 - g100 - g001 + g101) * (w * u)
+            + (g100 + g010 + g001 + g111 - g000 - g110 - g101 - g011) * (u * v * w)
+            + Vec3::new(
+                du * (k1 + k4 * v + k6 * w + k7 * v * w),
+                dv * (k2 + k5 * w + k4 * u + k7 * w * u),
+                dw * (k3 + k6 * u + k5 * v + k7 * u * v),
+            );
+
+        (value, gradient)
+    }
+}
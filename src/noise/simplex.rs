@@ -0,0 +1,195 @@
+use super::{cell, gradient_2, gradient_3, Noise, NoiseDerivative, Permutation};
+use crate::vec::{Vec2, Vec3, Vec4};
+
+/// Skewing factors between the simplex grid and the cubic lattice.
+const F2: f32 = 0.366_025_4;
+const G2: f32 = 0.211_324_87;
+const F3: f32 = 1.0 / 3.0;
+const G3: f32 = 1.0 / 6.0;
+const F4: f32 = 0.309_017;
+const G4: f32 = 0.138_196_6;
+
+/// Bring the sums of corner contributions, whose kernels have a radius
+/// squared of 0.5 so they vanish before reaching neighboring simplices,
+/// to roughly `[-1, 1]`.
+const SCALE_2: f32 = 99.0;
+const SCALE_3: f32 = 76.0;
+const SCALE_4: f32 = 62.0;
+
+/// Ken Perlin's simplex noise, following Stefan Gustavson's implementation.
+/// Roughly in `[-1, 1]`, with fewer directional artifacts than `Perlin` and
+/// cheaper in higher dimensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simplex {
+    permutation: Permutation,
+}
+
+impl Simplex {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+}
+
+fn gradient_4(hash: usize) -> Vec4 {
+    // Midpoints of the edges of a 4D hypercube: one zero, three signs.
+    let hash = hash & 31;
+    let zero = hash >> 3;
+    let mut components = [0.0; 4];
+    let mut bit = 0;
+    for (idx, component) in components.iter_mut().enumerate() {
+        if idx != zero {
+            *component = if hash & (1 << bit) == 0 { 1.0 } else { -1.0 };
+            bit += 1;
+        }
+    }
+    components.into()
+}
+
+impl Noise<Vec2> for Simplex {
+    fn sample(&self, point: Vec2) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec2> for Simplex {
+    fn sample_with_derivative(&self, point: Vec2) -> (f32, Vec2) {
+        let skew = (point.x + point.y) * F2;
+        let (i, _) = cell(point.x + skew);
+        let (j, _) = cell(point.y + skew);
+        let unskew = (i + j) as f32 * G2;
+        let d0 = Vec2::new(point.x - (i as f32 - unskew), point.y - (j as f32 - unskew));
+
+        let (i1, j1) = if d0.x > d0.y { (1, 0) } else { (0, 1) };
+        let corners = [
+            ((0, 0), d0),
+            (
+                (i1, j1),
+                d0 - Vec2::new(i1 as f32, j1 as f32) + Vec2::new(G2, G2),
+            ),
+            (
+                (1, 1),
+                d0 - Vec2::new(1.0, 1.0) + Vec2::new(2.0 * G2, 2.0 * G2),
+            ),
+        ];
+
+        let mut value = 0.0;
+        let mut gradient = Vec2::zero();
+        for ((x, y), offset) in corners {
+            let t = 0.5 - offset.magnitude_squared();
+            if t <= 0.0 {
+                continue;
+            }
+            let g = gradient_2(self.permutation.hash(&[i + x, j + y]));
+            let dot = g.dot(&offset);
+            let t2 = t * t;
+            value += t2 * t2 * dot;
+            gradient += offset * (-8.0 * t2 * t * dot) + g * (t2 * t2);
+        }
+
+        (value * SCALE_2, gradient * SCALE_2)
+    }
+}
+
+impl Noise<Vec3> for Simplex {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec3> for Simplex {
+    fn sample_with_derivative(&self, point: Vec3) -> (f32, Vec3) {
+        let skew = (point.x() + point.y() + point.z()) * F3;
+        let (i, _) = cell(point.x() + skew);
+        let (j, _) = cell(point.y() + skew);
+        let (k, _) = cell(point.z() + skew);
+        let unskew = (i + j + k) as f32 * G3;
+        let d0 = Vec3::new(
+            point.x() - (i as f32 - unskew),
+            point.y() - (j as f32 - unskew),
+            point.z() - (k as f32 - unskew),
+        );
+        let (x0, y0, z0) = (d0.x(), d0.y(), d0.z());
+
+        let (second, third) = if x0 >= y0 {
+            if y0 >= z0 {
+                ([1, 0, 0], [1, 1, 0])
+            } else if x0 >= z0 {
+                ([1, 0, 0], [1, 0, 1])
+            } else {
+                ([0, 0, 1], [1, 0, 1])
+            }
+        } else if y0 < z0 {
+            ([0, 0, 1], [0, 1, 1])
+        } else if x0 < z0 {
+            ([0, 1, 0], [0, 1, 1])
+        } else {
+            ([0, 1, 0], [1, 1, 0])
+        };
+
+        let mut value = 0.0;
+        let mut gradient = Vec3::zero();
+        for (idx, corner) in [[0, 0, 0], second, third, [1, 1, 1]]
+            .into_iter()
+            .enumerate()
+        {
+            let offset = d0 - Vec3::new(corner[0] as f32, corner[1] as f32, corner[2] as f32)
+                + Vec3::new(G3, G3, G3) * idx as f32;
+            let t = 0.5 - offset.magnitude_squared();
+            if t <= 0.0 {
+                continue;
+            }
+            let g =
+                gradient_3(
+                    self.permutation
+                        .hash(&[i + corner[0], j + corner[1], k + corner[2]]),
+                );
+            let dot = g.dot(&offset);
+            let t2 = t * t;
+            value += t2 * t2 * dot;
+            gradient += offset * (-8.0 * t2 * t * dot) + g * (t2 * t2);
+        }
+
+        (value * SCALE_3, gradient * SCALE_3)
+    }
+}
+
+impl Noise<Vec4> for Simplex {
+    fn sample(&self, point: Vec4) -> f32 {
+        let skew = (point.x() + point.y() + point.z() + point.w()) * F4;
+        let base: [i32; 4] = std::array::from_fn(|axis| cell(point.idx(axis) + skew).0);
+        let unskew = base.iter().sum::<i32>() as f32 * G4;
+        let d0: [f32; 4] =
+            std::array::from_fn(|axis| point.idx(axis) - (base[axis] as f32 - unskew));
+
+        // Rank the offsets; the simplex steps along the largest axis first.
+        let mut rank = [0; 4];
+        for a in 0..4 {
+            for b in (a + 1)..4 {
+                if d0[a] > d0[b] {
+                    rank[a] += 1;
+                } else {
+                    rank[b] += 1;
+                }
+            }
+        }
+
+        let mut value = 0.0;
+        for step in 0..5 {
+            let corner: [i32; 4] = std::array::from_fn(|axis| i32::from(rank[axis] + step >= 4));
+            let offset: [f32; 4] =
+                std::array::from_fn(|axis| d0[axis] - corner[axis] as f32 + step as f32 * G4);
+            let offset = Vec4::from(offset);
+            let t = 0.5 - offset.magnitude_squared();
+            if t <= 0.0 {
+                continue;
+            }
+            let coords: [i32; 4] = std::array::from_fn(|axis| base[axis] + corner[axis]);
+            let t2 = t * t;
+            value += t2 * t2 * gradient_4(self.permutation.hash(&coords)).dot(&offset);
+        }
+
+        value * SCALE_4
+    }
+}
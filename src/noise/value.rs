@@ -0,0 +1,86 @@
+use super::{cell, fade, Noise, NoiseDerivative, Permutation};
+use crate::vec::{Vec2, Vec3};
+
+/// Smoothly interpolated random values at lattice points, in `[-1, 1]`.
+/// Blockier than gradient noise, but cheap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Value {
+    permutation: Permutation,
+}
+
+impl Value {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+
+    fn lattice(&self, coords: &[i32]) -> f32 {
+        self.permutation.hash(coords) as f32 / 255.0 * 2.0 - 1.0
+    }
+}
+
+impl Noise<Vec2> for Value {
+    fn sample(&self, point: Vec2) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec2> for Value {
+    fn sample_with_derivative(&self, point: Vec2) -> (f32, Vec2) {
+        let (ix, fx) = cell(point.x);
+        let (iy, fy) = cell(point.y);
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+
+        let a = self.lattice(&[ix, iy]);
+        let b = self.lattice(&[ix + 1, iy]);
+        let c = self.lattice(&[ix, iy + 1]);
+        let d = self.lattice(&[ix + 1, iy + 1]);
+
+        let (k1, k2, k3) = (b - a, c - a, a - b - c + d);
+        (
+            a + k1 * u + k2 * v + k3 * u * v,
+            Vec2::new(du * (k1 + k3 * v), dv * (k2 + k3 * u)),
+        )
+    }
+}
+
+impl Noise<Vec3> for Value {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.sample_with_derivative(point).0
+    }
+}
+
+impl NoiseDerivative<Vec3> for Value {
+    fn sample_with_derivative(&self, point: Vec3) -> (f32, Vec3) {
+        let (ix, fx) = cell(point.x());
+        let (iy, fy) = cell(point.y());
+        let (iz, fz) = cell(point.z());
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+        let (w, dw) = fade(fz);
+
+        let n = |x: i32, y: i32, z: i32| self.lattice(&[ix + x, iy + y, iz + z]);
+        let n000 = n(0, 0, 0);
+        let k1 = n(1, 0, 0) - n000;
+        let k2 = n(0, 1, 0) - n000;
+        let k3 = n(0, 0, 1) - n000;
+        let k4 = n000 - n(1, 0, 0) - n(0, 1, 0) + n(1, 1, 0);
+        let k5 = n000 - n(0, 1, 0) - n(0, 0, 1) + n(0, 1, 1);
+        let k6 = n000 - n(1, 0, 0) - n(0, 0, 1) + n(1, 0, 1);
+        let k7 =
+            -n000 + n(1, 0, 0) + n(0, 1, 0) - n(1, 1, 0) + n(0, 0, 1) - n(1, 0, 1) - n(0, 1, 1)
+                + n(1, 1, 1);
+
+        let value =
+            n000 + k1 * u + k2 * v + k3 * w + k4 * u * v + k5 * v * w + k6 * w * u + k7 * u * v * w;
+        let gradient = Vec3::new(
+            du * (k1 + k4 * v + k6 * w + k7 * v * w),
+            dv * (k2 + k5 * w + k4 * u + k7 * w * u),
+            dw * (k3 + k6 * u + k5 * v + k7 * u * v),
+        );
+
+        (value, gradient)
+    }
+}
@@ -0,0 +1,93 @@
+use super::{cell, Noise, Permutation};
+use crate::vec::{Vec2, Vec3};
+
+/// Steven Worley's cellular noise: distances to the nearest feature points,
+/// one scattered in each lattice cell. Samples are the distance to the
+/// nearest point (F1), which lies in `[0, ~1.2]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worley {
+    permutation: Permutation,
+}
+
+impl Worley {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            permutation: Permutation::new(seed),
+        }
+    }
+
+    /// Offset of the feature point of a cell along `axis`, in `[0, 1)`.
+    fn feature(&self, coords: &[i32], axis: i32) -> f32 {
+        let high = self.permutation.hash(&[coords, &[axis]].concat());
+        let low = self.permutation.hash(&[coords, &[axis + 7, 3]].concat());
+        (high * 256 + low) as f32 / 65536.0
+    }
+
+    /// Distances to the nearest and second nearest feature points (F1, F2).
+    pub fn distances_2(&self, point: Vec2) -> (f32, f32) {
+        let (ix, fx) = cell(point.x);
+        let (iy, fy) = cell(point.y);
+
+        let mut nearest = (f32::INFINITY, f32::INFINITY);
+        for y in -1..=1 {
+            for x in -1..=1 {
+                let coords = [ix + x, iy + y];
+                let feature = Vec2::new(
+                    x as f32 + self.feature(&coords, 0),
+                    y as f32 + self.feature(&coords, 1),
+                );
+                insert(
+                    &mut nearest,
+                    (feature - Vec2::new(fx, fy)).magnitude_squared(),
+                );
+            }
+        }
+        (nearest.0.sqrt(), nearest.1.sqrt())
+    }
+
+    /// Distances to the nearest and second nearest feature points (F1, F2).
+    pub fn distances_3(&self, point: Vec3) -> (f32, f32) {
+        let (ix, fx) = cell(point.x());
+        let (iy, fy) = cell(point.y());
+        let (iz, fz) = cell(point.z());
+
+        let mut nearest = (f32::INFINITY, f32::INFINITY);
+        for z in -1..=1 {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    let coords = [ix + x, iy + y, iz + z];
+                    let feature = Vec3::new(
+                        x as f32 + self.feature(&coords, 0),
+                        y as f32 + self.feature(&coords, 1),
+                        z as f32 + self.feature(&coords, 2),
+                    );
+                    insert(
+                        &mut nearest,
+                        (feature - Vec3::new(fx, fy, fz)).magnitude_squared(),
+                    );
+                }
+            }
+        }
+        (nearest.0.sqrt(), nearest.1.sqrt())
+    }
+}
+
+fn insert(nearest: &mut (f32, f32), distance: f32) {
+    if distance < nearest.0 {
+        *nearest = (distance, nearest.0);
+    } else if distance < nearest.1 {
+        nearest.1 = distance;
+    }
+}
+
+impl Noise<Vec2> for Worley {
+    fn sample(&self, point: Vec2) -> f32 {
+        self.distances_2(point).0
+    }
+}
+
+impl Noise<Vec3> for Worley {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.distances_3(point).0
+    }
+}
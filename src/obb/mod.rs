@@ -0,0 +1,323 @@
+use crate::{
+    aabb::Aabb3,
+    mat::{Mat3, Mat4},
+    quat::Quat,
+    vec::Vec3,
+};
+use serde::Deserialize;
+
+/// `rotation` holds the box's local axes as columns.
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Obb3 {
+    center: Vec3,
+    half_extents: Vec3,
+    rotation: Mat3,
+}
+
+impl Obb3 {
+    pub fn new(center: Vec3, half_extents: Vec3, rotation: Mat3) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    pub fn from_quat(center: Vec3, half_extents: Vec3, rotation: Quat) -> Self {
+        Self::new(center, half_extents, Mat3::rotation_from_quat(rotation))
+    }
+
+    pub fn from_aabb(aabb: &Aabb3) -> Self {
+        Self::new(aabb.center(), aabb.extents(), Mat3::identity())
+    }
+
+    /// Fits a box aligned with the principal axes of the point covariance.
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let inv_count = 1.0 / points.len() as f32;
+        let mean = points.iter().fold(Vec3::zero(), |sum, point| sum + *point) * inv_count;
+
+        let mut covariance = [[0.0; 3]; 3];
+        for point in points {
+            let offset = *point - mean;
+            for (i, row) in covariance.iter_mut().enumerate() {
+                for (j, value) in row.iter_mut().enumerate() {
+                    *value += offset.idx(i) * offset.idx(j) * inv_count;
+                }
+            }
+        }
+
+        let (_, rotation) = Mat3::from(covariance).symmetric_eigen();
+        let axes = [rotation.col(0), rotation.col(1), rotation.col(2)];
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for point in points {
+            for (axis, direction) in axes.iter().enumerate() {
+                let projection = point.dot(direction);
+                min[axis] = min[axis].min(projection);
+                max[axis] = max[axis].max(projection);
+            }
+        }
+
+        let local_center = (Vec3::from(min) + Vec3::from(max)) * 0.5;
+        let half_extents = (Vec3::from(max) - Vec3::from(min)) * 0.5;
+        Some(Self::new(rotation * local_center, half_extents, rotation))
+    }
+
+    pub fn center(&self) -> Vec3 {
+        self.center
+    }
+
+    pub fn half_extents(&self) -> Vec3 {
+        self.half_extents
+    }
+
+    pub fn rotation(&self) -> Mat3 {
+        self.rotation
+    }
+
+    pub fn axis(&self, idx: usize) -> Vec3 {
+        self.rotation.col(idx)
+    }
+
+    pub fn volume(&self) -> f32 {
+        8.0 * self.half_extents.x() * self.half_extents.y() * self.half_extents.z()
+    }
+
+    pub fn to_local(&self, point: Vec3) -> Vec3 {
+        self.rotation.transposed() * (point - self.center)
+    }
+
+    pub fn to_world(&self, point: Vec3) -> Vec3 {
+        self.center + self.rotation * point
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        let local = self.to_local(point);
+        (0..3).all(|axis| local.idx(axis).abs() <= self.half_extents.idx(axis))
+    }
+
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let local = self.to_local(point);
+        let clamped = local
+            .max(&self.half_extents.negate())
+            .min(&self.half_extents);
+        self.to_world(clamped)
+    }
+
+    /// World-space corners, ordered as [`Aabb3::corners`] along the box's own axes.
+    pub fn corners(&self) -> [Vec3; 8] {
+        let local = Aabb3::from_center_extents(Vec3::zero(), self.half_extents).corners();
+        local.map(|corner| self.to_world(corner))
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb3 {
+        let extents = [0, 1, 2].map(|i| {
+            let row = self.rotation.row(i);
+            (0..3)
+                .map(|j| row.idx(j).abs() * self.half_extents.idx(j))
+                .sum::<f32>()
+        });
+        Aabb3::from_center_extents(self.center, extents.into())
+    }
+
+    /// Separating axis test over the 15 candidate axes (Ericson, RTCD 4.4.1).
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        const EPSILON: f32 = 1e-6;
+
+        let a = self.half_extents;
+        let b = rhs.half_extents;
+
+        let mut r = [[0.0; 3]; 3];
+        let mut abs_r = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = self.axis(i).dot(&rhs.axis(j));
+                // Padding keeps near-parallel edge pairs from producing a
+                // degenerate cross product axis.
+                abs_r[i][j] = r[i][j].abs() + EPSILON;
+            }
+        }
+
+        let offset = rhs.center - self.center;
+        let t = [
+            offset.dot(&self.axis(0)),
+            offset.dot(&self.axis(1)),
+            offset.dot(&self.axis(2)),
+        ];
+
+        for i in 0..3 {
+            let ra = a.idx(i);
+            let rb = b.x() * abs_r[i][0] + b.y() * abs_r[i][1] + b.z() * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        for j in 0..3 {
+            let ra = a.x() * abs_r[0][j] + a.y() * abs_r[1][j] + a.z() * abs_r[2][j];
+            let rb = b.idx(j);
+            if (t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j]).abs() > ra + rb {
+                return false;
+            }
+        }
+
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a.idx(i1) * abs_r[i2][j] + a.idx(i2) * abs_r[i1][j];
+                let rb = b.idx(j1) * abs_r[i][j2] + b.idx(j2) * abs_r[i][j1];
+                if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Exact for rotation, translation and uniform scale. Otherwise, as with
+    /// non-uniform scale of a rotated box, the transformed axes are skewed, so
+    /// they are re-orthonormalized and the box grows to enclose the result.
+    /// Flat boxes and collapsed axes keep a valid rotation.
+    pub fn transform(&self, mat: &Mat4) -> Self {
+        const EPSILON: f32 = 1e-12;
+
+        let center = mat.transform_point(self.center);
+        let edges =
+            [0, 1, 2].map(|i| mat.transform_vector(self.axis(i) * self.half_extents.idx(i)));
+
+        // Gram–Schmidt on the transformed unit axes, falling back to the
+        // untransformed axes where the transform collapses one.
+        let mut basis: Vec<Vec3> = Vec::with_capacity(3);
+        for i in 0..2 {
+            let candidates = [
+                mat.transform_vector(self.axis(i)),
+                self.axis(i),
+                self.axis(2),
+            ];
+            let axis = candidates
+                .into_iter()
+                .map(|candidate| {
+                    basis
+                        .iter()
+                        .fold(candidate, |vec, axis| vec - *axis * axis.dot(&vec))
+                })
+                .find(|vec| vec.magnitude_squared() > EPSILON)
+                .unwrap_or(self.axis(i));
+            basis.push(axis.normalized());
+        }
+        basis.push(basis[0].cross(&basis[1]));
+
+        let half_extents = [0, 1, 2].map(|k| {
+            edges
+                .iter()
+                .map(|edge| basis[k].dot(edge).abs())
+                .sum::<f32>()
+        });
+        Self::new(
+            center,
+            half_extents.into(),
+            Mat3::from_cols(basis[0], basis[1], basis[2]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn assert_orthonormal(rotation: Mat3) {
+        for i in 0..3 {
+            assert!((rotation.col(i).magnitude() - 1.0).abs() < 1e-5);
+            for j in i + 1..3 {
+                assert!(rotation.col(i).dot(&rotation.col(j)).abs() < 1e-5);
+            }
+        }
+        assert!((rotation.determinant() - 1.0).abs() < 1e-4);
+    }
+
+    fn corners(obb: &Obb3) -> Vec<Vec3> {
+        Aabb3::from_center_extents(Vec3::zero(), obb.half_extents())
+            .corners()
+            .iter()
+            .map(|corner| obb.to_world(*corner))
+            .collect()
+    }
+
+    fn rotated() -> Obb3 {
+        Obb3::from_quat(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(2.0, 1.0, 0.5),
+            Quat::from_axis_angle(Vec3::new(1.0, 1.0, 1.0).normalized(), 0.6),
+        )
+    }
+
+    #[test]
+    fn transform_rigid_is_exact() {
+        let obb = rotated();
+        let mat = Mat4::translate(Vec3::new(-3.0, 0.5, 2.0))
+            * Mat4::rotate(1.1, Vec3::new(0.0, 1.0, 0.0))
+            * Mat4::scale(Vec3::new(2.0, 2.0, 2.0));
+        let transformed = obb.transform(&mat);
+        assert_orthonormal(transformed.rotation());
+        assert_vec(transformed.half_extents(), obb.half_extents() * 2.0);
+        assert_vec(transformed.center(), mat.transform_point(obb.center()));
+        for i in 0..3 {
+            assert_vec(transformed.axis(i), mat.transform_vector(obb.axis(i)) * 0.5);
+        }
+    }
+
+    #[test]
+    fn transform_flat_box() {
+        let points = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+        ];
+        let flat = Obb3::from_points(&points).unwrap();
+        assert!(
+            flat.half_extents().idx(0) * flat.half_extents().idx(1) * flat.half_extents().idx(2)
+                < 1e-6
+        );
+
+        let transformed = flat.transform(&Mat4::rotate_x(0.5));
+        assert!(transformed.half_extents().magnitude().is_finite());
+        assert_orthonormal(transformed.rotation());
+        assert!((transformed.volume()).abs() < 1e-5);
+
+        // A zero scale collapses an axis entirely.
+        let collapsed = rotated().transform(&Mat4::scale(Vec3::new(1.0, 0.0, 1.0)));
+        assert_orthonormal(collapsed.rotation());
+    }
+
+    #[test]
+    fn transform_non_uniform_encloses() {
+        let obb = rotated();
+        let mat = Mat4::scale(Vec3::new(3.0, 0.5, 1.0));
+        let transformed = obb.transform(&mat);
+        assert_orthonormal(transformed.rotation());
+
+        for corner in corners(&obb) {
+            let point = mat.transform_point(corner);
+            let local = transformed.to_local(point);
+            for axis in 0..3 {
+                assert!(local.idx(axis).abs() <= transformed.half_extents().idx(axis) + 1e-4);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    mat::Mat4,
+    vec::{Vec3, Vec4},
+};
+use serde::Deserialize;
+
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlaneSide {
+    Front,
+    Back,
+    On,
+}
+
+/// Points `p` on the plane satisfy `normal.dot(p) + distance = 0`.
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Plane {
+    normal: Vec3,
+    distance: f32,
+}
+
+impl Plane {
+    pub fn new(normal: Vec3, distance: f32) -> Self {
+        Self { normal, distance }
+    }
+
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        let normal = normal.normalized();
+        Self::new(normal, -normal.dot(&point))
+    }
+
+    /// The normal faces the side from which `a`, `b`, `c` appear counter-clockwise.
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self::from_point_normal(a, (b - a).cross(&(c - a)))
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// A zero normal, such as the far plane of an infinite projection, is the
+    /// plane at infinity: it keeps the zero normal and gets an infinite
+    /// distance with the same sign, so every point is on the same side of it.
+    pub fn normalized(&self) -> Self {
+        let magnitude = self.normal.magnitude();
+        if magnitude == 0.0 {
+            return Self::new(self.normal, f32::INFINITY.copysign(self.distance));
+        }
+        Self::new(self.normal / magnitude, self.distance / magnitude)
+    }
+
+    pub fn flipped(&self) -> Self {
+        Self::new(self.normal.negate(), -self.distance)
+    }
+
+    /// Only a true distance when the plane is normalized.
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(&point) + self.distance
+    }
+
+    pub fn classify_point(&self, point: Vec3, epsilon: f32) -> PlaneSide {
+        let distance = self.signed_distance(point);
+        if distance > epsilon {
+            PlaneSide::Front
+        } else if distance < -epsilon {
+            PlaneSide::Back
+        } else {
+            PlaneSide::On
+        }
+    }
+
+    pub fn project_point(&self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    pub fn reflect_point(&self, point: Vec3) -> Vec3 {
+        point - self.normal * (2.0 * self.signed_distance(point))
+    }
+
+    /// Returns a point on the line shared by both planes and its direction.
+    pub fn intersect_plane(&self, rhs: &Self) -> Option<(Vec3, Vec3)> {
+        let direction = self.normal.cross(&rhs.normal);
+        let det = direction.magnitude_squared();
+        if det < EPSILON {
+            return None;
+        }
+
+        let point = (direction.cross(&rhs.normal) * self.distance
+            + self.normal.cross(&direction) * rhs.distance)
+            / det;
+        Some((point, direction))
+    }
+
+    pub fn intersect_planes(a: &Self, b: &Self, c: &Self) -> Option<Vec3> {
+        let n1_cross_n2 = a.normal.cross(&b.normal);
+        let det = n1_cross_n2.dot(&c.normal);
+        if det.abs() < EPSILON {
+            return None;
+        }
+
+        Some(
+            (c.normal.cross(&b.normal) * a.distance + a.normal.cross(&c.normal) * b.distance
+                - n1_cross_n2 * c.distance)
+                / det,
+        )
+    }
+
+    /// Planes transform as row vectors by the inverse of `mat`, which is the
+    /// same as multiplying by its inverse transpose.
+    pub fn transform(&self, mat: &Mat4) -> Self {
+        Plane::from(mat.inverse().transposed() * Vec4::from(*self)).normalized()
+    }
+}
+
+impl From<Vec4> for Plane {
+    fn from(vec: Vec4) -> Self {
+        Self::new(vec.xyz(), vec.w())
+    }
+}
+
+impl From<Plane> for Vec4 {
+    fn from(plane: Plane) -> Self {
+        Vec4::new(
+            plane.normal.x(),
+            plane.normal.y(),
+            plane.normal.z(),
+            plane.distance,
+        )
+    }
+}
@@ -0,0 +1,247 @@
+//! Boolean operations by overlaying both inputs: every edge is split where it
+//! meets another, each piece is kept if the operation's result differs on its
+//! two sides, and the kept pieces are linked back into rings. Working per
+//! edge piece rather than walking intersections makes shared, collinear and
+//! touching edges no different from any other edge.
+
+use super::{BooleanOp, Polygon2, Shape2};
+use crate::{
+    aabb::Aabb2,
+    segment::{Segment2, SegmentIntersection},
+    vec::Vec2,
+};
+use std::collections::HashSet;
+
+pub(super) fn boolean(a: &[Shape2], b: &[Shape2], op: BooleanOp) -> Vec<Shape2> {
+    let points: Vec<Vec2> = a
+        .iter()
+        .chain(b)
+        .flat_map(|shape| shape.rings())
+        .flat_map(|ring| ring.points().iter().copied())
+        .collect();
+    let Some(bounds) = Aabb2::from_points(&points) else {
+        return Vec::new();
+    };
+    let scale = bounds.size().magnitude().max(f32::EPSILON);
+    let snap_epsilon = scale * 1e-6;
+    let side_offset = scale * 1e-5;
+
+    let mut vertices = Vertices::new(snap_epsilon);
+    let edges = split_edges(a.iter().chain(b), &mut vertices);
+
+    let inside =
+        |shapes: &[Shape2], point: Vec2| shapes.iter().any(|shape| shape.contains_point(point));
+    let keep = |point: Vec2| {
+        let (in_a, in_b) = (inside(a, point), inside(b, point));
+        match op {
+            BooleanOp::Union => in_a || in_b,
+            BooleanOp::Intersection => in_a && in_b,
+            BooleanOp::Difference => in_a && !in_b,
+            BooleanOp::Xor => in_a != in_b,
+        }
+    };
+
+    // Orient every boundary piece so the result lies on its left.
+    let mut boundary = Vec::new();
+    for [start, end] in edges {
+        let (p, q) = (vertices.points[start], vertices.points[end]);
+        let direction = q - p;
+        let length = direction.magnitude();
+        let offset =
+            Vec2::new(-direction.y, direction.x) * (side_offset.min(length * 0.25) / length);
+        let midpoint = (p + q) * 0.5;
+
+        match (keep(midpoint + offset), keep(midpoint - offset)) {
+            (true, false) => boundary.push([start, end]),
+            (false, true) => boundary.push([end, start]),
+            _ => {}
+        }
+    }
+
+    let rings = link_rings(&vertices.points, &boundary);
+    assemble(rings, side_offset)
+}
+
+/// Points closer than `epsilon` are merged so nearly coincident
+/// intersections produce a single vertex.
+struct Vertices {
+    points: Vec<Vec2>,
+    epsilon: f32,
+}
+
+impl Vertices {
+    fn new(epsilon: f32) -> Self {
+        Self {
+            points: Vec::new(),
+            epsilon,
+        }
+    }
+
+    fn insert(&mut self, point: Vec2) -> usize {
+        let epsilon_squared = self.epsilon * self.epsilon;
+        if let Some(existing) = self
+            .points
+            .iter()
+            .position(|other| (*other - point).magnitude_squared() <= epsilon_squared)
+        {
+            return existing;
+        }
+        self.points.push(point);
+        self.points.len() - 1
+    }
+}
+
+fn split_edges<'a>(
+    shapes: impl Iterator<Item = &'a Shape2>,
+    vertices: &mut Vertices,
+) -> Vec<[usize; 2]> {
+    let segments: Vec<Segment2> = shapes
+        .flat_map(|shape| shape.rings())
+        .flat_map(|ring| ring.edges())
+        .filter(|edge| edge.length() > vertices.epsilon)
+        .collect();
+
+    let mut splits: Vec<Vec<Vec2>> = segments
+        .iter()
+        .map(|segment| vec![segment.start(), segment.end()])
+        .collect();
+    for i in 0..segments.len() {
+        for j in i + 1..segments.len() {
+            let crossings = match segments[i].intersect(&segments[j]) {
+                SegmentIntersection::None => continue,
+                SegmentIntersection::Point(point) => vec![point],
+                SegmentIntersection::Overlap(overlap) => vec![overlap.start(), overlap.end()],
+            };
+            splits[i].extend_from_slice(&crossings);
+            splits[j].extend_from_slice(&crossings);
+        }
+    }
+
+    let mut edges = HashSet::new();
+    for (segment, mut points) in segments.iter().zip(splits) {
+        points.sort_by(|a, b| segment.closest_t(*a).total_cmp(&segment.closest_t(*b)));
+        let indices: Vec<usize> = points
+            .into_iter()
+            .map(|point| vertices.insert(point))
+            .collect();
+        for pair in indices.windows(2) {
+            if pair[0] != pair[1] {
+                edges.insert([pair[0].min(pair[1]), pair[0].max(pair[1])]);
+            }
+        }
+    }
+
+    let mut edges: Vec<[usize; 2]> = edges.into_iter().collect();
+    edges.sort_unstable();
+    edges
+}
+
+/// Walks the directed boundary edges into closed rings. Where several edges
+/// leave one vertex the walk takes the sharpest left turn, which keeps
+/// regions that only touch at a point as separate rings.
+fn link_rings(points: &[Vec2], boundary: &[[usize; 2]]) -> Vec<Polygon2> {
+    let mut used = vec![false; boundary.len()];
+    let mut rings = Vec::new();
+
+    for first in 0..boundary.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut ring = Vec::new();
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let [start, end] = boundary[current];
+            ring.push(points[start]);
+
+            let reverse = points[start] - points[end];
+            let next = (0..boundary.len())
+                .filter(|candidate| boundary[*candidate][0] == end)
+                .filter(|candidate| !used[*candidate] || *candidate == first)
+                .min_by(|a, b| {
+                    let angle = |candidate: usize| {
+                        let direction = points[boundary[candidate][1]] - points[end];
+                        clockwise_angle(reverse, direction)
+                    };
+                    angle(*a).total_cmp(&angle(*b))
+                });
+
+            match next {
+                Some(next) if next != first => current = next,
+                _ => break,
+            }
+        }
+
+        let ring = remove_collinear(ring);
+        if ring.len() >= 3 {
+            rings.push(Polygon2::new(ring));
+        }
+    }
+
+    rings
+}
+
+/// Angle in `(0, 2π]` swept clockwise from `from` to `to`.
+fn clockwise_angle(from: Vec2, to: Vec2) -> f32 {
+    let counter_clockwise = from.cross(&to).atan2(from.dot(&to));
+    let clockwise = -counter_clockwise;
+    if clockwise <= 0.0 {
+        clockwise + std::f32::consts::TAU
+    } else {
+        clockwise
+    }
+}
+
+fn remove_collinear(mut ring: Vec<Vec2>) -> Vec<Vec2> {
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let len = ring.len();
+        let prev = ring[(i + len - 1) % len];
+        let current = ring[i];
+        let next = ring[(i + 1) % len];
+        let (incoming, outgoing) = (current - prev, next - current);
+        let cross = incoming.cross(&outgoing);
+        let tolerance = incoming.magnitude() * outgoing.magnitude() * 1e-6;
+        if cross.abs() <= tolerance && incoming.dot(&outgoing) > 0.0 {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    ring
+}
+
+/// Counter-clockwise rings are outlines; each clockwise ring becomes a hole
+/// of the smallest outline around it.
+fn assemble(rings: Vec<Polygon2>, side_offset: f32) -> Vec<Shape2> {
+    let (outlines, holes): (Vec<Polygon2>, Vec<Polygon2>) =
+        rings.into_iter().partition(|ring| ring.signed_area() > 0.0);
+
+    let mut owned_holes: Vec<Vec<Polygon2>> = vec![Vec::new(); outlines.len()];
+    for hole in holes {
+        // A point just inside the filled side of the hole's first edge.
+        let edge = hole.edges().next().unwrap_or_default();
+        let direction = edge.direction();
+        let offset = Vec2::new(-direction.y, direction.x)
+            * (side_offset.min(direction.magnitude() * 0.25) / direction.magnitude());
+        let sample = edge.at(0.5) + offset;
+
+        let owner = outlines
+            .iter()
+            .enumerate()
+            .filter(|(_, outline)| outline.contains_point(sample))
+            .min_by(|(_, a), (_, b)| a.area().total_cmp(&b.area()))
+            .map(|(i, _)| i);
+        if let Some(owner) = owner {
+            owned_holes[owner].push(hole);
+        }
+    }
+
+    outlines
+        .into_iter()
+        .zip(owned_holes)
+        .map(|(outline, holes)| Shape2::new(outline, holes))
+        .collect()
+}
@@ -0,0 +1,8 @@
+mod boolean;
+mod polygon2;
+mod shape2;
+
+pub use self::{
+    polygon2::Polygon2,
+    shape2::{BooleanOp, Shape2},
+};
@@ -0,0 +1,509 @@
+use crate::{
+    hull::convex_hull2,
+    point::Point2D,
+    segment::Segment2,
+    triangle::{Triangle2, Winding},
+    vec::Vec2,
+};
+use serde::Deserialize;
+
+/// A closed polygon; the last point connects back to the first.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(transparent)]
+pub struct Polygon2 {
+    points: Vec<Vec2>,
+}
+
+impl Polygon2 {
+    pub fn new(points: Vec<Vec2>) -> Self {
+        Self { points }
+    }
+
+    /// See [`convex_hull2`].
+    pub fn convex_hull(points: &[Vec2]) -> Self {
+        convex_hull2(points)
+    }
+
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Segment2> + '_ {
+        self.points
+            .iter()
+            .zip(self.points.iter().cycle().skip(1))
+            .map(|(start, end)| Segment2::new(*start, *end))
+    }
+
+    /// Positive for counter-clockwise polygons.
+    pub fn signed_area(&self) -> f32 {
+        self.edges()
+            .map(|edge| edge.start().cross(&edge.end()))
+            .sum::<f32>()
+            * 0.5
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    pub fn perimeter(&self) -> f32 {
+        self.edges().map(|edge| edge.length()).sum()
+    }
+
+    pub fn centroid(&self) -> Vec2 {
+        // Degenerate relative to the polygon's size, falling back to the
+        // vertex average.
+        let area = self.signed_area();
+        if area.abs() <= f32::EPSILON * self.perimeter().powi(2) {
+            return self
+                .points
+                .iter()
+                .fold(Vec2::zero(), |sum, point| sum + *point)
+                / self.points.len().max(1) as f32;
+        }
+
+        let sum = self.edges().fold(Vec2::zero(), |sum, edge| {
+            sum + (edge.start() + edge.end()) * edge.start().cross(&edge.end())
+        });
+        sum / (6.0 * area)
+    }
+
+    pub fn winding(&self) -> Winding {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Winding::CounterClockwise
+        } else if area < 0.0 {
+            Winding::Clockwise
+        } else {
+            Winding::Degenerate
+        }
+    }
+
+    pub fn reversed(&self) -> Self {
+        Self::new(self.points.iter().rev().copied().collect())
+    }
+
+    pub fn with_winding(&self, winding: Winding) -> Self {
+        if self.winding() == winding || self.winding() == Winding::Degenerate {
+            self.clone()
+        } else {
+            self.reversed()
+        }
+    }
+
+    /// Collinear vertices are allowed.
+    pub fn is_convex(&self) -> bool {
+        let len = self.points.len();
+        if len < 3 {
+            return false;
+        }
+
+        let mut sign = 0.0;
+        for i in 0..len {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % len];
+            let c = self.points[(i + 2) % len];
+            let turn = (b - a).cross(&(c - b));
+            if turn != 0.0 {
+                if turn * sign < 0.0 {
+                    return false;
+                }
+                sign = turn;
+            }
+        }
+        sign != 0.0
+    }
+
+    /// Number of times the polygon winds around `point`, counter-clockwise
+    /// being positive.
+    pub fn winding_number(&self, point: Vec2) -> i32 {
+        self.edges().fold(0, |winding_number, edge| {
+            let (a, b) = (edge.start(), edge.end());
+            let side = (b - a).cross(&(point - a));
+            if a.y <= point.y {
+                if b.y > point.y && side > 0.0 {
+                    return winding_number + 1;
+                }
+            } else if b.y <= point.y && side < 0.0 {
+                return winding_number - 1;
+            }
+            winding_number
+        })
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    /// Ramer–Douglas–Peucker simplification, dropping vertices that lie within
+    /// `epsilon` of the simplified outline.
+    pub fn simplify(&self, epsilon: f32) -> Self {
+        let len = self.points.len();
+        if len <= 3 {
+            return self.clone();
+        }
+
+        // Split the loop at the vertex furthest from the first so both chains
+        // have distinct endpoints.
+        let first = self.points[0];
+        let split = (1..len)
+            .max_by(|i, j| {
+                first
+                    .distance(self.points[*i])
+                    .total_cmp(&first.distance(self.points[*j]))
+            })
+            .unwrap_or(len / 2);
+
+        let mut closed = self.points.clone();
+        closed.push(first);
+
+        let mut keep = vec![false; len + 1];
+        keep[0] = true;
+        keep[split] = true;
+        keep[len] = true;
+        Self::douglas_peucker(&closed, 0, split, epsilon, &mut keep);
+        Self::douglas_peucker(&closed, split, len, epsilon, &mut keep);
+
+        Self::new(
+            (0..len)
+                .filter(|i| keep[*i])
+                .map(|i| self.points[i])
+                .collect(),
+        )
+    }
+
+    fn douglas_peucker(points: &[Vec2], start: usize, end: usize, epsilon: f32, keep: &mut [bool]) {
+        if end <= start + 1 {
+            return;
+        }
+
+        let segment = Segment2::new(points[start], points[end]);
+        let (furthest, distance) = (start + 1..end)
+            .map(|i| (i, segment.distance_to_point(points[i])))
+            .fold((start, 0.0), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+
+        if distance > epsilon {
+            keep[furthest] = true;
+            Self::douglas_peucker(points, start, furthest, epsilon, keep);
+            Self::douglas_peucker(points, furthest, end, epsilon, keep);
+        }
+    }
+
+    /// Sutherland–Hodgman clipping against a convex polygon of either winding.
+    /// Concave subjects may produce degenerate zero-width bridges.
+    pub fn clip_convex(&self, clip: &Polygon2) -> Self {
+        let clip = clip.with_winding(Winding::CounterClockwise);
+        let mut output = self.points.clone();
+
+        for edge in clip.edges() {
+            let input = std::mem::take(&mut output);
+            let side = |point: Vec2| edge.direction().cross(&(point - edge.start()));
+
+            for (i, current) in input.iter().enumerate() {
+                let prev = input[(i + input.len() - 1) % input.len()];
+                let (prev_side, current_side) = (side(prev), side(*current));
+                let crossing =
+                    || prev + (*current - prev) * (prev_side / (prev_side - current_side));
+
+                match (prev_side >= 0.0, current_side >= 0.0) {
+                    (true, true) => output.push(*current),
+                    (true, false) => output.push(crossing()),
+                    (false, true) => {
+                        output.push(crossing());
+                        output.push(*current);
+                    }
+                    (false, false) => {}
+                }
+            }
+        }
+
+        // Vertices lying exactly on a clip edge are emitted twice.
+        output.dedup();
+        if output.len() > 1 && output.first() == output.last() {
+            output.pop();
+        }
+        Self::new(output)
+    }
+
+    /// Ear-clipping triangulation. Indices refer to [`Polygon2::points`] and
+    /// triangles are counter-clockwise.
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        self.triangulate_with_holes(&[])
+    }
+
+    /// Ear-clipping triangulation of the region inside this polygon and
+    /// outside every hole. Indices refer to this polygon's points followed by
+    /// each hole's points in order. Holes are joined to the outline with
+    /// bridge edges (Eberly, "Triangulation by Ear Clipping").
+    pub fn triangulate_with_holes(&self, holes: &[Polygon2]) -> Vec<[usize; 3]> {
+        let mut positions = self.points.clone();
+        let mut outline: Vec<usize> = (0..self.points.len()).collect();
+        if self.winding() == Winding::Clockwise {
+            outline.reverse();
+        }
+
+        let mut hole_loops = Vec::new();
+        for hole in holes {
+            let offset = positions.len();
+            positions.extend_from_slice(&hole.points);
+            let mut hole_loop: Vec<usize> = (offset..positions.len()).collect();
+            if hole.winding() == Winding::CounterClockwise {
+                hole_loop.reverse();
+            }
+            if hole_loop.len() >= 3 {
+                hole_loops.push(hole_loop);
+            }
+        }
+
+        // Bridge holes from right to left so earlier bridges cannot block later ones.
+        hole_loops.sort_by(|a, b| {
+            let max_x = |hole: &Vec<usize>| {
+                hole.iter()
+                    .map(|i| positions[*i].x)
+                    .fold(f32::NEG_INFINITY, f32::max)
+            };
+            max_x(b).total_cmp(&max_x(a))
+        });
+        for hole in hole_loops {
+            Self::bridge_hole(&positions, &mut outline, &hole);
+        }
+
+        Self::clip_ears(&positions, outline)
+    }
+
+    fn bridge_hole(positions: &[Vec2], outline: &mut Vec<usize>, hole: &[usize]) {
+        let hole_start = (0..hole.len())
+            .max_by(|i, j| positions[hole[*i]].x.total_cmp(&positions[hole[*j]].x))
+            .unwrap_or(0);
+        let m = positions[hole[hole_start]];
+
+        // Closest edge hit by a ray from `m` towards +x.
+        let mut closest: Option<(f32, usize)> = None;
+        for i in 0..outline.len() {
+            let a = positions[outline[i]];
+            let b = positions[outline[(i + 1) % outline.len()]];
+            if a.y == b.y || m.y < a.y.min(b.y) || m.y > a.y.max(b.y) {
+                continue;
+            }
+
+            let t = (m.y - a.y) / (b.y - a.y);
+            let x = a.x + (b.x - a.x) * t;
+            if x >= m.x && x < closest.map_or(f32::INFINITY, |(best, _)| best) {
+                closest = Some((x, i));
+            }
+        }
+
+        let bridge = match closest {
+            Some((x, edge)) => {
+                let a = outline[edge];
+                let b = outline[(edge + 1) % outline.len()];
+                let candidate = if positions[a].x > positions[b].x {
+                    edge
+                } else {
+                    (edge + 1) % outline.len()
+                };
+                let hit = Vec2::new(x, m.y);
+                let p = positions[outline[candidate]];
+
+                if p == hit {
+                    candidate
+                } else {
+                    // A reflex vertex inside the triangle (m, hit, p) would
+                    // block the bridge; use the one closest in angle to the ray.
+                    let triangle = Triangle2::new(m, hit, p);
+                    let mut best = candidate;
+                    let mut best_angle = f32::NEG_INFINITY;
+                    let mut best_distance = f32::INFINITY;
+                    for i in 0..outline.len() {
+                        let point = positions[outline[i]];
+                        if point == p
+                            || !triangle.contains_point(point)
+                            || !Self::is_reflex(positions, outline, i)
+                        {
+                            continue;
+                        }
+                        let offset = point - m;
+                        let distance = offset.magnitude();
+                        let angle = offset.x / distance;
+                        if angle > best_angle || (angle == best_angle && distance < best_distance) {
+                            best = i;
+                            best_angle = angle;
+                            best_distance = distance;
+                        }
+                    }
+                    best
+                }
+            }
+            None => 0,
+        };
+
+        let mut spliced = Vec::with_capacity(outline.len() + hole.len() + 2);
+        spliced.extend_from_slice(&outline[..=bridge]);
+        spliced.extend(hole[hole_start..].iter().chain(&hole[..=hole_start]));
+        spliced.extend_from_slice(&outline[bridge..]);
+        *outline = spliced;
+    }
+
+    fn is_reflex(positions: &[Vec2], outline: &[usize], i: usize) -> bool {
+        Self::turn(positions, outline, i) < 0.0
+    }
+
+    fn clip_ears(positions: &[Vec2], mut outline: Vec<usize>) -> Vec<[usize; 3]> {
+        let mut triangles = Vec::with_capacity(outline.len().saturating_sub(2));
+
+        while outline.len() > 3 {
+            let len = outline.len();
+            let ear = (0..len).find(|i| Self::is_ear(positions, &outline, *i));
+
+            // Degenerate input can leave no valid ear; clip the flattest
+            // vertex so the loop always terminates.
+            let i = ear.unwrap_or_else(|| {
+                (0..len)
+                    .min_by(|a, b| {
+                        Self::turn(positions, &outline, *a)
+                            .abs()
+                            .total_cmp(&Self::turn(positions, &outline, *b).abs())
+                    })
+                    .unwrap_or(0)
+            });
+
+            let prev = outline[(i + len - 1) % len];
+            let next = outline[(i + 1) % len];
+            let triangle = Triangle2::new(positions[prev], positions[outline[i]], positions[next]);
+            if triangle.area() > 0.0 {
+                triangles.push([prev, outline[i], next]);
+            }
+            outline.remove(i);
+        }
+
+        if let [a, b, c] = outline[..] {
+            if Triangle2::new(positions[a], positions[b], positions[c]).area() > 0.0 {
+                triangles.push([a, b, c]);
+            }
+        }
+
+        triangles
+    }
+
+    fn turn(positions: &[Vec2], outline: &[usize], i: usize) -> f32 {
+        let len = outline.len();
+        let prev = positions[outline[(i + len - 1) % len]];
+        let current = positions[outline[i]];
+        let next = positions[outline[(i + 1) % len]];
+        (current - prev).cross(&(next - current))
+    }
+
+    fn is_ear(positions: &[Vec2], outline: &[usize], i: usize) -> bool {
+        if Self::turn(positions, outline, i) <= 0.0 {
+            return false;
+        }
+
+        let len = outline.len();
+        let a = positions[outline[(i + len - 1) % len]];
+        let b = positions[outline[i]];
+        let c = positions[outline[(i + 1) % len]];
+        let triangle = Triangle2::new(a, b, c);
+
+        // Bridges duplicate vertices, so compare positions rather than indices.
+        outline.iter().all(|index| {
+            let point = positions[*index];
+            point == a || point == b || point == c || !triangle.contains_point(point)
+        })
+    }
+}
+
+impl From<Vec<Vec2>> for Polygon2 {
+    fn from(points: Vec<Vec2>) -> Self {
+        Self::new(points)
+    }
+}
+
+impl From<&[Point2D<f32>]> for Polygon2 {
+    fn from(points: &[Point2D<f32>]) -> Self {
+        Self::new(
+            points
+                .iter()
+                .map(|point| Vec2::new(point.x(), point.y()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangulated_area(points: &[Vec2], triangles: &[[usize; 3]]) -> f32 {
+        triangles
+            .iter()
+            .map(|[a, b, c]| Triangle2::new(points[*a], points[*b], points[*c]).area())
+            .sum()
+    }
+
+    fn polygon(points: &[(f32, f32)]) -> Polygon2 {
+        Polygon2::new(points.iter().map(|(x, y)| Vec2::new(*x, *y)).collect())
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        // An "E" shape with three prongs.
+        let shape = polygon(&[
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (3.0, 2.0),
+            (3.0, 3.0),
+            (1.0, 3.0),
+            (1.0, 4.0),
+            (4.0, 4.0),
+            (4.0, 5.0),
+            (0.0, 5.0),
+        ]);
+        let triangles = shape.triangulate();
+        assert_eq!(triangles.len(), shape.len() - 2);
+        assert!((triangulated_area(shape.points(), &triangles) - shape.area()).abs() < 1e-4);
+
+        let reversed = shape.reversed();
+        let triangles = reversed.triangulate();
+        assert!((triangulated_area(reversed.points(), &triangles) - shape.area()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn triangulate_with_holes() {
+        let outline = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+        let holes = [
+            polygon(&[(1.0, 1.0), (4.0, 1.0), (4.0, 4.0), (1.0, 4.0)]),
+            polygon(&[(6.0, 6.0), (6.0, 9.0), (9.0, 9.0), (9.0, 6.0)]),
+            polygon(&[(6.0, 1.0), (9.0, 2.0), (7.0, 4.0)]),
+        ];
+
+        let mut points = outline.points().to_vec();
+        for hole in &holes {
+            points.extend_from_slice(hole.points());
+        }
+
+        let triangles = outline.triangulate_with_holes(&holes);
+        let expected = outline.area() - holes.iter().map(Polygon2::area).sum::<f32>();
+        assert!((triangulated_area(&points, &triangles) - expected).abs() < 1e-3);
+        for [a, b, c] in &triangles {
+            assert!(Triangle2::new(points[*a], points[*b], points[*c]).signed_area() > 0.0);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use super::{boolean, Polygon2};
+use crate::{triangle::Winding, vec::Vec2};
+use serde::Deserialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+/// A polygon with holes. The outline winds counter-clockwise and the holes
+/// clockwise.
+#[derive(Debug, Deserialize, Default, Clone, PartialEq)]
+#[serde(from = "RawShape2")]
+pub struct Shape2 {
+    outline: Polygon2,
+    holes: Vec<Polygon2>,
+}
+
+/// Deserialized rings in any winding, normalized through [`Shape2::new`].
+#[derive(Deserialize)]
+struct RawShape2 {
+    outline: Polygon2,
+    holes: Vec<Polygon2>,
+}
+
+impl Shape2 {
+    pub fn new(outline: Polygon2, holes: Vec<Polygon2>) -> Self {
+        Self {
+            outline: outline.with_winding(Winding::CounterClockwise),
+            holes: holes
+                .into_iter()
+                .map(|hole| hole.with_winding(Winding::Clockwise))
+                .collect(),
+        }
+    }
+
+    pub fn outline(&self) -> &Polygon2 {
+        &self.outline
+    }
+
+    pub fn holes(&self) -> &[Polygon2] {
+        &self.holes
+    }
+
+    pub fn rings(&self) -> impl Iterator<Item = &Polygon2> {
+        std::iter::once(&self.outline).chain(&self.holes)
+    }
+
+    pub fn area(&self) -> f32 {
+        self.rings().map(|ring| ring.signed_area()).sum()
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.rings()
+            .map(|ring| ring.winding_number(point))
+            .sum::<i32>()
+            != 0
+    }
+
+    /// Indices refer to the outline's points followed by each hole's points.
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        self.outline.triangulate_with_holes(&self.holes)
+    }
+
+    /// Applies `op` to the regions covered by `a` and `b`, where each side may
+    /// be made of several disjoint shapes.
+    pub fn boolean(a: &[Shape2], b: &[Shape2], op: BooleanOp) -> Vec<Shape2> {
+        boolean::boolean(a, b, op)
+    }
+
+    pub fn union(&self, rhs: &Self) -> Vec<Shape2> {
+        Self::boolean(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            BooleanOp::Union,
+        )
+    }
+
+    pub fn intersection(&self, rhs: &Self) -> Vec<Shape2> {
+        Self::boolean(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            BooleanOp::Intersection,
+        )
+    }
+
+    pub fn difference(&self, rhs: &Self) -> Vec<Shape2> {
+        Self::boolean(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            BooleanOp::Difference,
+        )
+    }
+
+    pub fn xor(&self, rhs: &Self) -> Vec<Shape2> {
+        Self::boolean(
+            std::slice::from_ref(self),
+            std::slice::from_ref(rhs),
+            BooleanOp::Xor,
+        )
+    }
+}
+
+impl From<RawShape2> for Shape2 {
+    fn from(raw: RawShape2) -> Self {
+        Self::new(raw.outline, raw.holes)
+    }
+}
+
+impl From<Polygon2> for Shape2 {
+    fn from(outline: Polygon2) -> Self {
+        Self::new(outline, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Shape2 {
+        Polygon2::new(vec![
+            Vec2::new(min_x, min_y),
+            Vec2::new(max_x, min_y),
+            Vec2::new(max_x, max_y),
+            Vec2::new(min_x, max_y),
+        ])
+        .into()
+    }
+
+    fn total_area(shapes: &[Shape2]) -> f32 {
+        shapes.iter().map(Shape2::area).sum()
+    }
+
+    fn assert_area(shapes: &[Shape2], expected: f32) {
+        let area = total_area(shapes);
+        assert!(
+            (area - expected).abs() < 1e-4,
+            "area {area}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn overlapping_squares() {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let b = rect(1.0, 1.0, 3.0, 3.0);
+        assert_area(&a.union(&b), 7.0);
+        assert_area(&a.intersection(&b), 1.0);
+        assert_area(&a.difference(&b), 3.0);
+        assert_area(&b.difference(&a), 3.0);
+        assert_area(&a.xor(&b), 6.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert!(union[0].holes().is_empty());
+        assert!(union[0].contains_point(Vec2::new(2.5, 2.5)));
+        assert!(!union[0].contains_point(Vec2::new(2.5, 0.5)));
+    }
+
+    #[test]
+    fn overlapping_squares_small_scale() {
+        let scale = 1e-4;
+        let a = rect(0.0, 0.0, 2.0 * scale, 2.0 * scale);
+        let b = rect(scale, scale, 3.0 * scale, 3.0 * scale);
+        let assert_scaled_area = |shapes: &[Shape2], expected: f32| {
+            let area = total_area(shapes) / (scale * scale);
+            assert!(
+                (area - expected).abs() < 1e-3,
+                "area {area}, expected {expected}"
+            );
+        };
+        assert_scaled_area(&a.union(&b), 7.0);
+        assert_scaled_area(&a.intersection(&b), 1.0);
+        assert_scaled_area(&a.difference(&b), 3.0);
+        assert_scaled_area(&b.difference(&a), 3.0);
+        assert_scaled_area(&a.xor(&b), 6.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert!(union[0].holes().is_empty());
+        assert_eq!(union[0].outline().len(), 8);
+        assert!(union[0].contains_point(Vec2::new(2.5, 2.5) * scale));
+        assert!(!union[0].contains_point(Vec2::new(2.5, 0.5) * scale));
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.len(), 1);
+        let centroid = difference[0].outline().centroid();
+        assert!((centroid - Vec2::new(2.5, 2.5) * (scale / 3.0)).magnitude() < 1e-3 * scale);
+    }
+
+    #[test]
+    fn shared_edge() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.0, 0.0, 2.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_area(&union, 2.0);
+        assert_area(&a.intersection(&b), 0.0);
+        assert_area(&a.difference(&b), 1.0);
+        assert_area(&a.xor(&b), 2.0);
+    }
+
+    #[test]
+    fn collinear_edges() {
+        // Bottom edges overlap along part of their length.
+        let a = rect(0.0, 0.0, 2.0, 1.0);
+        let b = rect(1.0, 0.0, 3.0, 2.0);
+        assert_area(&a.union(&b), 5.0);
+        assert_area(&a.intersection(&b), 1.0);
+        assert_area(&a.difference(&b), 1.0);
+        assert_area(&b.difference(&a), 3.0);
+        assert_area(&a.xor(&b), 4.0);
+    }
+
+    #[test]
+    fn identical_inputs() {
+        let a = rect(0.0, 0.0, 2.0, 3.0);
+        assert_area(&a.union(&a), 6.0);
+        assert_area(&a.intersection(&a), 6.0);
+        assert_area(&a.difference(&a), 0.0);
+        assert_area(&a.xor(&a), 0.0);
+    }
+
+    #[test]
+    fn contained_shape_makes_hole() {
+        let outer = rect(0.0, 0.0, 4.0, 4.0);
+        let inner = rect(1.0, 1.0, 2.0, 2.0);
+
+        let difference = outer.difference(&inner);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0].holes().len(), 1);
+        assert_eq!(difference[0].holes()[0].winding(), Winding::Clockwise);
+        assert_area(&difference, 15.0);
+        assert!(!difference[0].contains_point(Vec2::new(1.5, 1.5)));
+        assert!(difference[0].contains_point(Vec2::new(3.0, 3.0)));
+
+        assert_area(&outer.union(&inner), 16.0);
+        assert_area(&outer.intersection(&inner), 1.0);
+        assert_area(&inner.difference(&outer), 0.0);
+        assert_area(&outer.xor(&inner), 15.0);
+    }
+
+    #[test]
+    fn new_normalizes_winding() {
+        let outline = Polygon2::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 4.0),
+            Vec2::new(4.0, 4.0),
+            Vec2::new(4.0, 0.0),
+        ]);
+        let hole = Polygon2::new(vec![
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 2.0),
+        ]);
+        let shape = Shape2::new(outline, vec![hole]);
+        assert_eq!(shape.outline().winding(), Winding::CounterClockwise);
+        assert_eq!(shape.holes()[0].winding(), Winding::Clockwise);
+        assert!((shape.area() - 15.0).abs() < 1e-5);
+    }
+}
@@ -1 +1,3 @@
 mod quad3;
+
+pub use self::quad3::Quad3D;
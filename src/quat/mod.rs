@@ -1,4 +1,4 @@
-use crate::vec::Vec3;
+use crate::{mat::Mat3, vec::Vec3};
 use serde::Deserialize;
 
 #[repr(C)]
@@ -8,6 +8,12 @@ pub struct Quat {
 }
 
 impl Quat {
+    pub fn identity() -> Self {
+        Self {
+            data: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+
     pub fn from_parts(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self { data: [x, y, z, w] }
     }
@@ -25,6 +31,47 @@ impl Quat {
         Self { data: [x, y, z, w] }
     }
 
+    /// Rotation of an orthonormal, right-handed matrix (Shepperd's method).
+    pub fn from_mat3(mat: &Mat3) -> Self {
+        let (m00, m11, m22) = (mat.idx(0, 0), mat.idx(1, 1), mat.idx(2, 2));
+        let trace = m00 + m11 + m22;
+
+        let quat = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::from_parts(
+                (mat.idx(2, 1) - mat.idx(1, 2)) / s,
+                (mat.idx(0, 2) - mat.idx(2, 0)) / s,
+                (mat.idx(1, 0) - mat.idx(0, 1)) / s,
+                0.25 * s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            Self::from_parts(
+                0.25 * s,
+                (mat.idx(0, 1) + mat.idx(1, 0)) / s,
+                (mat.idx(0, 2) + mat.idx(2, 0)) / s,
+                (mat.idx(2, 1) - mat.idx(1, 2)) / s,
+            )
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            Self::from_parts(
+                (mat.idx(0, 1) + mat.idx(1, 0)) / s,
+                0.25 * s,
+                (mat.idx(1, 2) + mat.idx(2, 1)) / s,
+                (mat.idx(0, 2) - mat.idx(2, 0)) / s,
+            )
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            Self::from_parts(
+                (mat.idx(0, 2) + mat.idx(2, 0)) / s,
+                (mat.idx(1, 2) + mat.idx(2, 1)) / s,
+                0.25 * s,
+                (mat.idx(1, 0) - mat.idx(0, 1)) / s,
+            )
+        };
+        quat.normalized()
+    }
+
     pub fn x(&self) -> f32 {
         self.data[0]
     }
@@ -71,6 +118,37 @@ impl Quat {
         *self / self.magnitude()
     }
 
+    pub fn dot(&self, rhs: &Self) -> f32 {
+        self.x() * rhs.x() + self.y() * rhs.y() + self.z() * rhs.z() + self.w() * rhs.w()
+    }
+
+    /// The same rotation, on the opposite side of the 4D sphere.
+    pub fn negate(&self) -> Self {
+        *self * -1.0
+    }
+
+    /// Spherical interpolation along the shorter arc. Nearly parallel inputs
+    /// fall back to normalized linear interpolation.
+    pub fn slerp(&self, rhs: Self, t: f32) -> Self {
+        let mut cos_theta = self.dot(&rhs);
+        let rhs = if cos_theta < 0.0 {
+            cos_theta = -cos_theta;
+            rhs.negate()
+        } else {
+            rhs
+        };
+
+        if cos_theta > 0.9995 {
+            return (*self * (1.0 - t) + rhs * t).normalized();
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((1.0 - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        *self * a + rhs * b
+    }
+
     pub fn inverse(self) -> Self {
         Self {
             data: [-self.x(), -self.y(), -self.z(), self.w()],
@@ -94,13 +172,28 @@ impl std::ops::Mul<Quat> for Quat {
             data: [
                 q1.w() * q2.x() + q1.x() * q2.w() + q1.y() * q2.z() - q1.z() * q2.y(),
                 q1.w() * q2.y() - q1.x() * q2.z() + q1.y() * q2.w() + q1.z() * q2.x(),
-                q1.w() * q2.z() + q1.x() * q2.y() - q1.y() * q2.z() + q1.z() * q2.w(),
+                q1.w() * q2.z() + q1.x() * q2.y() - q1.y() * q2.x() + q1.z() * q2.w(),
                 q1.w() * q2.w() - q1.x() * q2.x() - q1.y() * q2.y() - q1.z() * q2.z(),
             ],
         }
     }
 }
 
+impl std::ops::Add<Quat> for Quat {
+    type Output = Self;
+
+    fn add(self, rhs: Quat) -> Self::Output {
+        Self {
+            data: [
+                self.x() + rhs.x(),
+                self.y() + rhs.y(),
+                self.z() + rhs.z(),
+                self.w() + rhs.w(),
+            ],
+        }
+    }
+}
+
 impl std::ops::Mul<f32> for Quat {
     type Output = Self;
 
@@ -130,3 +223,58 @@ impl std::ops::Div<f32> for Quat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4};
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn mul_composes_rotations() {
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let z = Vec3::new(0.0, 0.0, 1.0);
+        let about_x = Quat::from_axis_angle(x, FRAC_PI_2);
+        let about_z = Quat::from_axis_angle(z, FRAC_PI_2);
+
+        // `about_x` first turns y onto z, which `about_z` leaves alone.
+        assert_vec((about_z * about_x).rotate_vector(y), z);
+        assert_vec((about_z * about_x).rotate_vector(x), y);
+        assert_vec((about_x * about_z).rotate_vector(x), z);
+        assert_vec((about_x * about_z).rotate_vector(y), x.negate());
+    }
+
+    #[test]
+    fn mul_matches_sequential_rotation() {
+        let rotations = [
+            Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), FRAC_PI_3),
+            Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), -FRAC_PI_4),
+            Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 2.0),
+            Quat::from_axis_angle(Vec3::new(1.0, 2.0, -3.0).normalized(), 0.7),
+            Quat::from_axis_angle(Vec3::new(-2.0, 0.5, 1.0).normalized(), -2.5),
+        ];
+        let vectors = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.3, -1.2, 2.0),
+        ];
+
+        for a in rotations {
+            for b in rotations {
+                for v in vectors {
+                    assert_vec(
+                        (a * b).rotate_vector(v),
+                        a.rotate_vector(b.rotate_vector(v)),
+                    );
+                }
+            }
+        }
+    }
+}
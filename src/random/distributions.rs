@@ -0,0 +1,141 @@
+use super::{warp, RandomSource};
+use crate::{
+    aabb::Aabb3,
+    quat::Quat,
+    triangle::Triangle3,
+    vec::{Vec2, Vec3},
+};
+use std::f32::consts::TAU;
+
+/// A distribution of values of type `T`.
+pub trait Sample<T> {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T;
+}
+
+/// Uniform over the area of the unit disk.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UnitDisk;
+
+/// Uniform over the unit circle.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UnitCircle;
+
+/// Uniform over the volume of the unit ball.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UnitBall;
+
+/// Uniform over the surface of the unit sphere.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UnitSphere;
+
+/// Unit directions, uniform over the hemisphere around `normal`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hemisphere {
+    pub normal: Vec3,
+}
+
+/// Unit directions around `normal`, with density proportional to the cosine
+/// of their angle to it, which matches Lambertian reflection.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CosineHemisphere {
+    pub normal: Vec3,
+}
+
+/// Uniform over the area of a triangle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InTriangle(pub Triangle3);
+
+/// Uniform over the volume of a box.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InAabb(pub Aabb3);
+
+/// Rotations uniform over SO(3) (Shoemake).
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UnitQuat;
+
+/// Unit directions, uniform over the solid angle within `half_angle`
+/// radians of `axis`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Cone {
+    pub axis: Vec3,
+    pub half_angle: f32,
+}
+
+/// Two uniform numbers in `[0, 1)`, to feed the warps.
+fn square<R: RandomSource + ?Sized>(rng: &mut R) -> Vec2 {
+    let x = rng.next_f32();
+    Vec2::new(x, rng.next_f32())
+}
+
+impl Sample<Vec2> for UnitDisk {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        warp::square_to_disk(square(rng))
+    }
+}
+
+impl Sample<Vec2> for UnitCircle {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        warp::square_to_circle(square(rng))
+    }
+}
+
+impl Sample<Vec3> for UnitBall {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let radius = rng.next_f32().cbrt();
+        UnitSphere.sample(rng) * radius
+    }
+}
+
+impl Sample<Vec3> for UnitSphere {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        warp::square_to_sphere(square(rng))
+    }
+}
+
+impl Sample<Vec3> for Hemisphere {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        warp::to_frame(self.normal, warp::square_to_hemisphere(square(rng)))
+    }
+}
+
+impl Sample<Vec3> for CosineHemisphere {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        warp::to_frame(self.normal, warp::square_to_cosine_hemisphere(square(rng)))
+    }
+}
+
+impl Sample<Vec3> for InTriangle {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let weights = warp::square_to_triangle(square(rng));
+        self.0.a() * weights.x() + self.0.b() * weights.y() + self.0.c() * weights.z()
+    }
+}
+
+impl Sample<Vec3> for InAabb {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let (min, max) = (self.0.min(), self.0.max());
+        Vec3::new(
+            rng.range(min.x(), max.x()),
+            rng.range(min.y(), max.y()),
+            rng.range(min.z(), max.z()),
+        )
+    }
+}
+
+impl Sample<Quat> for UnitQuat {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Quat {
+        let u = rng.next_f32();
+        let (a, b) = (rng.next_f32() * TAU, rng.next_f32() * TAU);
+        let (low, high) = ((1.0 - u).sqrt(), u.sqrt());
+        Quat::from_parts(low * a.sin(), low * a.cos(), high * b.sin(), high * b.cos())
+    }
+}
+
+impl Sample<Vec3> for Cone {
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        warp::to_frame(
+            self.axis,
+            warp::square_to_cone(square(rng), self.half_angle),
+        )
+    }
+}
@@ -0,0 +1,103 @@
+mod distributions;
+mod pcg;
+mod poisson;
+mod sequence;
+mod warp;
+
+pub use self::{
+    distributions::{
+        Cone, CosineHemisphere, Hemisphere, InAabb, InTriangle, Sample, UnitBall, UnitCircle,
+        UnitDisk, UnitQuat, UnitSphere,
+    },
+    pcg::Pcg32,
+    poisson::poisson_disk,
+    sequence::{halton, halton_2, halton_3, hammersley, r2, radical_inverse, Sobol},
+    warp::{
+        square_to_circle, square_to_cone, square_to_cosine_hemisphere, square_to_disk,
+        square_to_hemisphere, square_to_sphere, square_to_triangle, to_frame,
+    },
+};
+
+/// Source of uniformly distributed random bits.
+pub trait RandomSource {
+    fn next_u32(&mut self) -> u32;
+
+    /// Uniform in `[0, 1)`, using the top 24 bits.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Uniform in `[min, max)`.
+    fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+}
+
+/// Lets any `rand` generator drive the distributions in this module.
+#[cfg(feature = "rand")]
+struct RandSource<'a, R: ?Sized>(&'a mut R);
+
+#[cfg(feature = "rand")]
+impl<R: rand::RngCore + ?Sized> RandomSource for RandSource<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+}
+
+#[cfg(feature = "rand")]
+macro_rules! impl_distribution {
+    ($($distribution:ty => $output:ty),* $(,)?) => {
+        $(
+            impl rand::distributions::Distribution<$output> for $distribution {
+                fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $output {
+                    Sample::sample(self, &mut RandSource(rng))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "rand")]
+impl_distribution!(
+    UnitDisk => crate::vec::Vec2,
+    UnitCircle => crate::vec::Vec2,
+    UnitBall => crate::vec::Vec3,
+    UnitSphere => crate::vec::Vec3,
+    Hemisphere => crate::vec::Vec3,
+    CosineHemisphere => crate::vec::Vec3,
+    InTriangle => crate::vec::Vec3,
+    InAabb => crate::vec::Vec3,
+    UnitQuat => crate::quat::Quat,
+    Cone => crate::vec::Vec3,
+);
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        RandomSource::next_u32(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let low = u64::from(RandomSource::next_u32(self));
+        let high = u64::from(RandomSource::next_u32(self));
+        (high << 32) | low
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = RandomSource::next_u32(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
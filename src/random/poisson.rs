@@ -0,0 +1,85 @@
+use super::RandomSource;
+use crate::{aabb::Aabb2, vec::Vec2};
+use std::f32::consts::{SQRT_2, TAU};
+
+/// Candidates tried around each point before it is retired.
+const ATTEMPTS: u32 = 30;
+
+/// Bridson's Poisson-disk sampling: points filling `bounds` with no two
+/// closer than `radius`, and no gap large enough for another.
+pub fn poisson_disk<R: RandomSource + ?Sized>(
+    bounds: &Aabb2,
+    radius: f32,
+    rng: &mut R,
+) -> Vec<Vec2> {
+    let size = bounds.size();
+    if radius <= 0.0 || size.x <= 0.0 || size.y <= 0.0 {
+        return Vec::new();
+    }
+
+    // Cells small enough that each holds at most one point.
+    let cell_size = radius / SQRT_2;
+    let columns = (size.x / cell_size).ceil() as usize;
+    let rows = (size.y / cell_size).ceil() as usize;
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    let cell = |point: Vec2| {
+        let local = point - bounds.min();
+        (
+            ((local.x / cell_size) as usize).min(columns - 1),
+            ((local.y / cell_size) as usize).min(rows - 1),
+        )
+    };
+
+    let mut points = Vec::new();
+    let mut active = Vec::new();
+    let first = Vec2::new(
+        rng.range(bounds.min().x, bounds.max().x),
+        rng.range(bounds.min().y, bounds.max().y),
+    );
+    let (column, row) = cell(first);
+    grid[row * columns + column] = Some(0);
+    points.push(first);
+    active.push(0);
+
+    while !active.is_empty() {
+        let slot = (rng.next_u32() as usize) % active.len();
+        let center = points[active[slot]];
+
+        let found = (0..ATTEMPTS).find_map(|_| {
+            // Uniform over the annulus between `radius` and twice it.
+            let distance = (radius * radius * (1.0 + 3.0 * rng.next_f32())).sqrt();
+            let angle = rng.next_f32() * TAU;
+            let candidate = center + Vec2::new(angle.cos(), angle.sin()) * distance;
+            if !bounds.contains(candidate) {
+                return None;
+            }
+
+            let (column, row) = cell(candidate);
+            let neighbors = (row.saturating_sub(2)..(row + 3).min(rows)).flat_map(|row| {
+                (column.saturating_sub(2)..(column + 3).min(columns))
+                    .map(move |column| row * columns + column)
+            });
+            for idx in neighbors {
+                if let Some(other) = grid[idx] {
+                    if (points[other] - candidate).magnitude_squared() < radius * radius {
+                        return None;
+                    }
+                }
+            }
+            Some((candidate, row * columns + column))
+        });
+
+        match found {
+            Some((candidate, idx)) => {
+                grid[idx] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+            }
+            None => {
+                active.swap_remove(slot);
+            }
+        }
+    }
+
+    points
+}
@@ -0,0 +1,160 @@
+use crate::vec::{Vec2, Vec3};
+
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Just below 1, so sequences stay within `[0, 1)` after rounding to `f32`.
+const ONE_MINUS_EPSILON: f32 = 1.0 - f32::EPSILON / 2.0;
+
+/// Mirrors the digits of `index` in `base` about the radix point. Panics if
+/// `base` is less than 2.
+pub fn radical_inverse(index: u32, base: u32) -> f32 {
+    assert!(base >= 2, "radical inverse needs a base of at least 2");
+    if base == 2 {
+        return (index.reverse_bits() as f64 / 4_294_967_296.0) as f32;
+    }
+
+    let inv_base = 1.0 / base as f64;
+    let mut index = index;
+    let mut reversed = 0u64;
+    let mut inv_base_n = 1.0;
+    while index > 0 {
+        reversed = reversed * base as u64 + (index % base) as u64;
+        inv_base_n *= inv_base;
+        index /= base;
+    }
+    ((reversed as f64 * inv_base_n) as f32).min(ONE_MINUS_EPSILON)
+}
+
+/// Component `dimension` of the `index`th Halton point, which uses the
+/// `dimension`th prime as its base. Supports the first 16 dimensions and
+/// panics beyond them; past about six, consecutive dimensions correlate
+/// noticeably.
+pub fn halton(index: u32, dimension: usize) -> f32 {
+    radical_inverse(index, PRIMES[dimension])
+}
+
+pub fn halton_2(index: u32) -> Vec2 {
+    Vec2::new(halton(index, 0), halton(index, 1))
+}
+
+pub fn halton_3(index: u32) -> Vec3 {
+    Vec3::new(halton(index, 0), halton(index, 1), halton(index, 2))
+}
+
+/// The `index`th of `count` Hammersley points. Better distributed than
+/// Halton, but the count must be known up front. Panics if `count` is zero.
+pub fn hammersley(index: u32, count: u32) -> Vec2 {
+    assert!(count > 0, "hammersley set needs at least one point");
+    Vec2::new(index as f32 / count as f32, radical_inverse(index, 2))
+}
+
+/// Martin Roberts' R2 sequence, an additive recurrence based on the
+/// plastic number. Very even, and any prefix of it is well distributed.
+pub fn r2(index: u32) -> Vec2 {
+    const PLASTIC: f64 = 1.324_717_957_244_746;
+    const A1: f64 = 1.0 / PLASTIC;
+    const A2: f64 = 1.0 / (PLASTIC * PLASTIC);
+
+    let index = index as f64;
+    Vec2::new(
+        ((0.5 + A1 * index).fract() as f32).min(ONE_MINUS_EPSILON),
+        ((0.5 + A2 * index).fract() as f32).min(ONE_MINUS_EPSILON),
+    )
+}
+
+/// Sobol' sequence over its first `Sobol::MAX_DIMENSIONS` dimensions, with
+/// Joe and Kuo's direction numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sobol {
+    directions: Vec<[u32; 32]>,
+}
+
+/// Degree, coefficients and initial direction numbers of the primitive
+/// polynomials for dimensions after the first (Joe–Kuo `new-joe-kuo-6.21201`).
+const POLYNOMIALS: [(u32, u32, &[u32]); 7] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+];
+
+impl Sobol {
+    pub const MAX_DIMENSIONS: usize = POLYNOMIALS.len() + 1;
+
+    /// Returns `None` if more than `MAX_DIMENSIONS` are requested.
+    pub fn new(dimensions: usize) -> Option<Self> {
+        if dimensions > Self::MAX_DIMENSIONS {
+            return None;
+        }
+
+        let mut directions = Vec::with_capacity(dimensions);
+        if dimensions > 0 {
+            directions.push(std::array::from_fn(|bit| 1 << (31 - bit)));
+        }
+        for &(degree, coefficients, initial) in
+            POLYNOMIALS.iter().take(dimensions.saturating_sub(1))
+        {
+            let degree = degree as usize;
+            let mut v = [0u32; 32];
+            for (bit, m) in initial.iter().enumerate() {
+                v[bit] = m << (31 - bit);
+            }
+            for bit in degree..32 {
+                v[bit] = v[bit - degree] ^ (v[bit - degree] >> degree);
+                for k in 1..degree {
+                    if (coefficients >> (degree - 1 - k)) & 1 == 1 {
+                        v[bit] ^= v[bit - k];
+                    }
+                }
+            }
+            directions.push(v);
+        }
+
+        Some(Self { directions })
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.directions.len()
+    }
+
+    /// Component `dimension` of the `index`th point. Panics if `dimension`
+    /// is not below [`Sobol::dimensions`].
+    pub fn sample(&self, index: u32, dimension: usize) -> f32 {
+        self.sample_scrambled(index, dimension, 0)
+    }
+
+    /// As `sample`, with random digit scrambling: XORing with `scramble`
+    /// decorrelates sequences while keeping their distribution. Panics if
+    /// `dimension` is not below [`Sobol::dimensions`].
+    pub fn sample_scrambled(&self, index: u32, dimension: usize, scramble: u32) -> f32 {
+        let directions = &self.directions[dimension];
+        let mut result = scramble;
+        let mut index = index;
+        let mut bit = 0;
+        while index > 0 {
+            if index & 1 == 1 {
+                result ^= directions[bit];
+            }
+            index >>= 1;
+            bit += 1;
+        }
+        ((result as f64 / 4_294_967_296.0) as f32).min(ONE_MINUS_EPSILON)
+    }
+
+    /// The first two dimensions.
+    pub fn sample_2(&self, index: u32) -> Vec2 {
+        Vec2::new(self.sample(index, 0), self.sample(index, 1))
+    }
+
+    /// The first three dimensions.
+    pub fn sample_3(&self, index: u32) -> Vec3 {
+        Vec3::new(
+            self.sample(index, 0),
+            self.sample(index, 1),
+            self.sample(index, 2),
+        )
+    }
+}
@@ -0,0 +1,82 @@
+//! Maps from the unit square `[0, 1)²` onto other domains. Each preserves
+//! relative area, so uniform or low-discrepancy points on the square stay
+//! uniform on the target.
+
+use crate::vec::{Vec2, Vec3};
+use std::f32::consts::{FRAC_PI_4, PI, TAU};
+
+/// Shirley and Chiu's concentric map, which keeps neighboring points close
+/// and so preserves the stratification of its input better than a polar map.
+pub fn square_to_disk(point: Vec2) -> Vec2 {
+    let (x, y) = (2.0 * point.x - 1.0, 2.0 * point.y - 1.0);
+    if x == 0.0 && y == 0.0 {
+        return Vec2::zero();
+    }
+
+    let (radius, angle) = if x.abs() > y.abs() {
+        (x, FRAC_PI_4 * (y / x))
+    } else {
+        (y, PI / 2.0 - FRAC_PI_4 * (x / y))
+    };
+    Vec2::new(radius * angle.cos(), radius * angle.sin())
+}
+
+pub fn square_to_circle(point: Vec2) -> Vec2 {
+    let angle = point.x * TAU;
+    Vec2::new(angle.cos(), angle.sin())
+}
+
+/// Onto the unit sphere, by Archimedes' hat-box theorem.
+pub fn square_to_sphere(point: Vec2) -> Vec3 {
+    let z = 1.0 - 2.0 * point.x;
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+    let angle = point.y * TAU;
+    Vec3::new(radius * angle.cos(), radius * angle.sin(), z)
+}
+
+/// Onto the hemisphere around +Z.
+pub fn square_to_hemisphere(point: Vec2) -> Vec3 {
+    let z = point.x;
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+    let angle = point.y * TAU;
+    Vec3::new(radius * angle.cos(), radius * angle.sin(), z)
+}
+
+/// Onto the hemisphere around +Z with cosine-weighted density, by lifting
+/// the concentric disk map (Malley's method).
+pub fn square_to_cosine_hemisphere(point: Vec2) -> Vec3 {
+    let disk = square_to_disk(point);
+    let z = (1.0 - disk.magnitude_squared()).max(0.0).sqrt();
+    Vec3::new(disk.x, disk.y, z)
+}
+
+/// Onto the directions within `half_angle` radians of +Z, uniform in solid angle.
+pub fn square_to_cone(point: Vec2, half_angle: f32) -> Vec3 {
+    let z = 1.0 - point.x * (1.0 - half_angle.cos());
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+    let angle = point.y * TAU;
+    Vec3::new(radius * angle.cos(), radius * angle.sin(), z)
+}
+
+/// Onto barycentric weights, uniform over a triangle's area.
+pub fn square_to_triangle(point: Vec2) -> Vec3 {
+    let root = point.x.sqrt();
+    Vec3::new(1.0 - root, root * (1.0 - point.y), root * point.y)
+}
+
+/// Rotates a direction given around +Z to the same direction around `normal`.
+pub fn to_frame(normal: Vec3, local: Vec3) -> Vec3 {
+    // Duff et al.'s branchless orthonormal basis.
+    let normal = normal.normalized();
+    let sign = 1f32.copysign(normal.z());
+    let a = -1.0 / (sign + normal.z());
+    let b = normal.x() * normal.y() * a;
+    let tangent = Vec3::new(
+        1.0 + sign * normal.x() * normal.x() * a,
+        sign * b,
+        -sign * normal.x(),
+    );
+    let bitangent = Vec3::new(b, sign + normal.y() * normal.y() * a, -normal.y());
+
+    tangent * local.x() + bitangent * local.y() + normal * local.z()
+}
@@ -0,0 +1,3 @@
+mod ray3;
+
+pub use self::ray3::{Ray3, RayHit};
@@ -0,0 +1,371 @@
+use crate::{
+    aabb::Aabb3, mat::Mat4, obb::Obb3, plane::Plane, quad::Quad3D, rect::Rect3D, sphere::Sphere,
+    vec::Vec3,
+};
+use serde::Deserialize;
+
+const EPSILON: f32 = 1e-7;
+
+/// `distance` is the ray parameter of the hit, which is the actual distance
+/// only when the ray direction is normalized. `normal` always faces the ray.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayHit {
+    pub distance: f32,
+    pub normal: Vec3,
+}
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Ray3 {
+    origin: Vec3,
+    direction: Vec3,
+}
+
+impl Ray3 {
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    pub fn origin(&self) -> Vec3 {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vec3 {
+        self.direction
+    }
+
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Hit distances are preserved by the transform, so a ray moved into
+    /// object space with the inverse world matrix reports world-space `t`s.
+    pub fn transform(&self, mat: &Mat4) -> Self {
+        Self::new(
+            mat.transform_point(self.origin),
+            mat.transform_vector(self.direction),
+        )
+    }
+
+    fn facing(&self, normal: Vec3) -> Vec3 {
+        if normal.dot(&self.direction) > 0.0 {
+            normal.negate()
+        } else {
+            normal
+        }
+    }
+
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<RayHit> {
+        let denom = plane.normal().dot(&self.direction);
+        if denom.abs() < EPSILON {
+            return None;
+        }
+
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(RayHit {
+            distance: t,
+            normal: self.facing(plane.normal()),
+        })
+    }
+
+    /// If the origin is inside the sphere the exit point is returned, with the
+    /// normal still facing the ray.
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<RayHit> {
+        let (center, radius) = (sphere.center(), sphere.radius());
+        let m = self.origin - center;
+        let a = self.direction.magnitude_squared();
+        let b = m.dot(&self.direction);
+        let c = m.magnitude_squared() - radius * radius;
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 || a < EPSILON {
+            return None;
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        let mut t = (-b - sqrt_discriminant) / a;
+        if t < 0.0 {
+            t = (-b + sqrt_discriminant) / a;
+        }
+
+        Some(RayHit {
+            distance: t,
+            normal: self.facing((self.at(t) - center) / radius),
+        })
+    }
+
+    /// Slab method. If the origin is inside the box the exit point is returned,
+    /// with the normal still facing the ray. A zero direction never hits.
+    pub fn intersect_aabb(&self, aabb: &Aabb3) -> Option<RayHit> {
+        let mut t_near = f32::NEG_INFINITY;
+        let mut t_far = f32::INFINITY;
+        let mut near_axis = 0;
+        let mut far_axis = 0;
+
+        for axis in 0..3 {
+            let origin = self.origin.idx(axis);
+            let direction = self.direction.idx(axis);
+            let min = aabb.min().idx(axis);
+            let max = aabb.max().idx(axis);
+
+            if direction.abs() < EPSILON {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv_direction = 1.0 / direction;
+            let t1 = (min - origin) * inv_direction;
+            let t2 = (max - origin) * inv_direction;
+            let (t1, t2) = if t1 > t2 { (t2, t1) } else { (t1, t2) };
+
+            if t1 > t_near {
+                t_near = t1;
+                near_axis = axis;
+            }
+            if t2 < t_far {
+                t_far = t2;
+                far_axis = axis;
+            }
+            if t_near > t_far {
+                return None;
+            }
+        }
+
+        // A zero direction inside the box never reaches a face.
+        if t_far < 0.0 || t_far == f32::INFINITY {
+            return None;
+        }
+
+        let (t, axis) = if t_near >= 0.0 {
+            (t_near, near_axis)
+        } else {
+            (t_far, far_axis)
+        };
+        let mut normal = [0.0; 3];
+        normal[axis] = -self.direction.idx(axis).signum();
+
+        Some(RayHit {
+            distance: t,
+            normal: normal.into(),
+        })
+    }
+
+    pub fn intersect_obb(&self, obb: &Obb3) -> Option<RayHit> {
+        let rotation = obb.rotation();
+        let local = Self::new(
+            obb.to_local(self.origin),
+            rotation.transposed() * self.direction,
+        );
+        let hit = local.intersect_aabb(&Aabb3::from_center_extents(
+            Vec3::zero(),
+            obb.half_extents(),
+        ))?;
+
+        Some(RayHit {
+            distance: hit.distance,
+            normal: rotation * hit.normal,
+        })
+    }
+
+    /// Möller–Trumbore. Also returns the barycentric weights of `a`, `b` and
+    /// `c` at the hit point.
+    pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<(RayHit, Vec3)> {
+        let edge_1 = b - a;
+        let edge_2 = c - a;
+
+        let p = self.direction.cross(&edge_2);
+        let det = edge_1.dot(&p);
+        if det.abs() < EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+
+        let s = self.origin - a;
+        let u = s.dot(&p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(&edge_1);
+        let v = self.direction.dot(&q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = edge_2.dot(&q) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+
+        let hit = RayHit {
+            distance: t,
+            normal: self.facing(edge_1.cross(&edge_2).normalized()),
+        };
+        Some((hit, Vec3::new(1.0 - u - v, u, v)))
+    }
+
+    fn intersect_depth_rect(&self, top_left: Vec3, bottom_right: Vec3) -> Option<RayHit> {
+        let hit = self.intersect_plane(&Plane::new(Vec3::new(0.0, 0.0, 1.0), -top_left.z()))?;
+        let point = self.at(hit.distance);
+
+        let (min_x, max_x) = (
+            top_left.x().min(bottom_right.x()),
+            top_left.x().max(bottom_right.x()),
+        );
+        let (min_y, max_y) = (
+            top_left.y().min(bottom_right.y()),
+            top_left.y().max(bottom_right.y()),
+        );
+        if point.x() < min_x || point.x() > max_x || point.y() < min_y || point.y() > max_y {
+            return None;
+        }
+
+        Some(hit)
+    }
+
+    pub fn intersect_rect(&self, rect: &Rect3D<f32>) -> Option<RayHit> {
+        self.intersect_depth_rect(rect.top_left().into(), rect.bottom_right().into())
+    }
+
+    pub fn intersect_quad(&self, quad: &Quad3D<f32>) -> Option<RayHit> {
+        self.intersect_depth_rect(quad.top_left().into(), quad.bottom_right().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point::Point3D, size::Size2D};
+
+    fn assert_hit(hit: Option<RayHit>, distance: f32, normal: Vec3) {
+        let hit = hit.expect("expected a hit");
+        assert!(
+            (hit.distance - distance).abs() < 1e-5,
+            "distance {}",
+            hit.distance
+        );
+        assert!(
+            (hit.normal - normal).magnitude() < 1e-5,
+            "normal {:?}",
+            hit.normal
+        );
+    }
+
+    fn x() -> Vec3 {
+        Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn plane() {
+        let plane = Plane::new(Vec3::new(0.0, 1.0, 0.0), -2.0);
+        let down = Ray3::new(Vec3::new(1.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0));
+        assert_hit(down.intersect_plane(&plane), 3.0, Vec3::new(0.0, 1.0, 0.0));
+
+        let up = Ray3::new(Vec3::zero(), Vec3::new(0.0, 2.0, 0.0));
+        assert_hit(up.intersect_plane(&plane), 1.0, Vec3::new(0.0, -1.0, 0.0));
+
+        let away = Ray3::new(Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(away.intersect_plane(&plane), None);
+        let parallel = Ray3::new(Vec3::new(0.0, 5.0, 0.0), x());
+        assert_eq!(parallel.intersect_plane(&plane), None);
+    }
+
+    #[test]
+    fn sphere() {
+        let sphere = Sphere::new(Vec3::new(5.0, 0.0, 0.0), 1.0);
+        let ray = Ray3::new(Vec3::zero(), x());
+        assert_hit(ray.intersect_sphere(&sphere), 4.0, x().negate());
+
+        let inside = Ray3::new(Vec3::new(5.0, 0.0, 0.0), x());
+        assert_hit(inside.intersect_sphere(&sphere), 1.0, x().negate());
+
+        let behind = Ray3::new(Vec3::new(10.0, 0.0, 0.0), x());
+        assert_eq!(behind.intersect_sphere(&sphere), None);
+        let miss = Ray3::new(Vec3::new(0.0, 1.5, 0.0), x());
+        assert_eq!(miss.intersect_sphere(&sphere), None);
+    }
+
+    #[test]
+    fn aabb() {
+        let aabb = Aabb3::new(Vec3::new(1.0, -1.0, -1.0), Vec3::new(3.0, 1.0, 1.0));
+        let ray = Ray3::new(Vec3::zero(), x());
+        assert_hit(ray.intersect_aabb(&aabb), 1.0, x().negate());
+
+        let diagonal = Ray3::new(Vec3::new(0.0, 2.5, 0.0), Vec3::new(1.0, -1.0, 0.0));
+        assert_hit(
+            diagonal.intersect_aabb(&aabb),
+            1.5,
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+
+        let inside = Ray3::new(Vec3::new(2.0, 0.0, 0.0), x());
+        assert_hit(inside.intersect_aabb(&aabb), 1.0, x().negate());
+
+        // Parallel to a slab: a hit inside it, a miss outside it.
+        let grazing = Ray3::new(Vec3::new(0.0, 0.5, 0.0), x());
+        assert_hit(grazing.intersect_aabb(&aabb), 1.0, x().negate());
+        let parallel = Ray3::new(Vec3::new(0.0, 2.0, 0.0), x());
+        assert_eq!(parallel.intersect_aabb(&aabb), None);
+
+        let behind = Ray3::new(Vec3::new(4.0, 0.0, 0.0), x());
+        assert_eq!(behind.intersect_aabb(&aabb), None);
+
+        let still = Ray3::new(Vec3::new(2.0, 0.0, 0.0), Vec3::zero());
+        assert_eq!(still.intersect_aabb(&aabb), None);
+    }
+
+    #[test]
+    fn triangle() {
+        let (a, b, c) = (
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 2.0, 0.0),
+        );
+        let ray = Ray3::new(Vec3::new(0.5, 0.5, 3.0), Vec3::new(0.0, 0.0, -1.0));
+        let (hit, weights) = ray.intersect_triangle(a, b, c).unwrap();
+        assert_hit(Some(hit), 3.0, Vec3::new(0.0, 0.0, 1.0));
+        assert!((weights - Vec3::new(0.5, 0.25, 0.25)).magnitude() < 1e-5);
+
+        let below = Ray3::new(Vec3::new(0.5, 0.5, -3.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_hit(
+            below.intersect_triangle(a, b, c).map(|(hit, _)| hit),
+            3.0,
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+
+        let outside = Ray3::new(Vec3::new(1.5, 1.5, 3.0), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(outside.intersect_triangle(a, b, c), None);
+        let parallel = Ray3::new(Vec3::new(-1.0, 0.5, 0.0), x());
+        assert_eq!(parallel.intersect_triangle(a, b, c), None);
+        let behind = Ray3::new(Vec3::new(0.5, 0.5, 3.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(behind.intersect_triangle(a, b, c), None);
+    }
+
+    #[test]
+    fn rect_and_quad() {
+        let top_left = Point3D::new(-1.0, -1.0, 4.0);
+        let size = Size2D::new(2.0, 3.0);
+        let rect = Rect3D::from_top_left(top_left, size);
+        let quad = Quad3D::from_top_left(top_left, size);
+
+        let hit = Ray3::new(Vec3::new(0.5, 1.5, 0.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_hit(hit.intersect_rect(&rect), 4.0, Vec3::new(0.0, 0.0, -1.0));
+        assert_hit(hit.intersect_quad(&quad), 4.0, Vec3::new(0.0, 0.0, -1.0));
+
+        let miss = Ray3::new(Vec3::new(0.5, 2.5, 0.0), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_rect(&rect), None);
+        assert_eq!(miss.intersect_quad(&quad), None);
+
+        let parallel = Ray3::new(Vec3::new(0.0, 0.0, 4.0), x());
+        assert_eq!(parallel.intersect_rect(&rect), None);
+    }
+}
@@ -0,0 +1,7 @@
+mod segment2;
+mod segment3;
+
+pub use self::{
+    segment2::{Segment2, SegmentIntersection},
+    segment3::Segment3,
+};
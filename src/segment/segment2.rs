@@ -0,0 +1,226 @@
+use crate::vec::Vec2;
+use serde::Deserialize;
+
+/// Relative tolerance: parallel and collinear tests compare against the
+/// segment lengths, so the results do not depend on the coordinate scale.
+const EPSILON: f32 = 1e-6;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SegmentIntersection {
+    None,
+    Point(Vec2),
+    /// Collinear segments sharing more than a single point.
+    Overlap(Segment2),
+}
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Segment2 {
+    start: Vec2,
+    end: Vec2,
+}
+
+impl Segment2 {
+    pub fn new(start: Vec2, end: Vec2) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> Vec2 {
+        self.start
+    }
+
+    pub fn end(&self) -> Vec2 {
+        self.end
+    }
+
+    /// Unnormalized, from `start` to `end`.
+    pub fn direction(&self) -> Vec2 {
+        self.end - self.start
+    }
+
+    pub fn length(&self) -> f32 {
+        self.direction().magnitude()
+    }
+
+    pub fn at(&self, t: f32) -> Vec2 {
+        self.start + self.direction() * t
+    }
+
+    /// Parameter in `[0, 1]` of the point on the segment closest to `point`.
+    pub fn closest_t(&self, point: Vec2) -> f32 {
+        let direction = self.direction();
+        let length_squared = direction.magnitude_squared();
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        ((point - self.start).dot(&direction) / length_squared).clamp(0.0, 1.0)
+    }
+
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.at(self.closest_t(point))
+    }
+
+    pub fn distance_squared_to_point(&self, point: Vec2) -> f32 {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    pub fn distance_to_point(&self, point: Vec2) -> f32 {
+        self.distance_squared_to_point(point).sqrt()
+    }
+
+    /// Closest pair of points, one on each segment.
+    pub fn closest_points(&self, rhs: &Self) -> (Vec2, Vec2) {
+        match self.intersect(rhs) {
+            SegmentIntersection::Point(point) => return (point, point),
+            SegmentIntersection::Overlap(overlap) => return (overlap.start, overlap.start),
+            SegmentIntersection::None => {}
+        }
+
+        // Without an intersection one of the closest points is an endpoint.
+        [
+            (self.start, rhs.closest_point(self.start)),
+            (self.end, rhs.closest_point(self.end)),
+            (self.closest_point(rhs.start), rhs.start),
+            (self.closest_point(rhs.end), rhs.end),
+        ]
+        .into_iter()
+        .min_by(|a, b| {
+            (a.0 - a.1)
+                .magnitude_squared()
+                .total_cmp(&(b.0 - b.1).magnitude_squared())
+        })
+        .unwrap_or((self.start, rhs.start))
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        self.intersect(rhs) != SegmentIntersection::None
+    }
+
+    pub fn intersect(&self, rhs: &Self) -> SegmentIntersection {
+        let r = self.direction();
+        let s = rhs.direction();
+        let offset = rhs.start - self.start;
+        let denom = r.cross(&s);
+
+        let (r_length, s_length) = (r.magnitude(), s.magnitude());
+        if r_length == 0.0 {
+            return Self::point_on(self.start, rhs);
+        }
+        if s_length == 0.0 {
+            return Self::point_on(rhs.start, self);
+        }
+
+        if denom.abs() <= EPSILON * r_length * s_length {
+            // `offset × r / |r|` is the distance of `rhs` from this line.
+            if offset.cross(&r).abs() > EPSILON * r_length * r_length.max(s_length) {
+                return SegmentIntersection::None;
+            }
+
+            let length_squared = r.magnitude_squared();
+            let t0 = offset.dot(&r) / length_squared;
+            let t1 = t0 + s.dot(&r) / length_squared;
+            let low = t0.min(t1).max(0.0);
+            let high = t0.max(t1).min(1.0);
+
+            return if low > high {
+                SegmentIntersection::None
+            } else if high - low <= EPSILON {
+                SegmentIntersection::Point(self.at(low))
+            } else {
+                SegmentIntersection::Overlap(Self::new(self.at(low), self.at(high)))
+            };
+        }
+
+        let t = offset.cross(&s) / denom;
+        let u = offset.cross(&r) / denom;
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            SegmentIntersection::Point(self.at(t))
+        } else {
+            SegmentIntersection::None
+        }
+    }
+
+    fn point_on(point: Vec2, segment: &Self) -> SegmentIntersection {
+        let tolerance = EPSILON * segment.length();
+        if segment.distance_squared_to_point(point) <= tolerance * tolerance {
+            SegmentIntersection::Point(point)
+        } else {
+            SegmentIntersection::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: (f32, f32), end: (f32, f32), scale: f32) -> Segment2 {
+        Segment2::new(
+            Vec2::new(start.0, start.1) * scale,
+            Vec2::new(end.0, end.1) * scale,
+        )
+    }
+
+    #[test]
+    fn intersect_is_scale_independent() {
+        for scale in [1.0, 1e-4, 1e-6, 1e4] {
+            let a = segment((0.0, 0.0), (2.0, 0.0), scale);
+
+            let crossing = segment((1.0, -1.0), (1.0, 1.0), scale);
+            match a.intersect(&crossing) {
+                SegmentIntersection::Point(point) => {
+                    assert!((point - Vec2::new(1.0, 0.0) * scale).magnitude() < 1e-5 * scale)
+                }
+                other => panic!("crossing at {scale}: {other:?}"),
+            }
+
+            let parallel = segment((0.0, 0.5), (2.0, 0.5), scale);
+            assert_eq!(a.intersect(&parallel), SegmentIntersection::None, "{scale}");
+
+            let collinear = segment((1.0, 0.0), (3.0, 0.0), scale);
+            match a.intersect(&collinear) {
+                SegmentIntersection::Overlap(overlap) => {
+                    assert!((overlap.length() - scale).abs() < 1e-5 * scale)
+                }
+                other => panic!("collinear at {scale}: {other:?}"),
+            }
+
+            let touching = segment((2.0, 0.0), (3.0, 1.0), scale);
+            assert!(a.intersects(&touching), "touching at {scale}");
+
+            let apart = segment((2.5, 0.0), (3.0, 0.0), scale);
+            assert_eq!(a.intersect(&apart), SegmentIntersection::None, "{scale}");
+
+            let nearly = segment((1.0, 0.1), (1.0, 1.0), scale);
+            assert!(!a.intersects(&nearly), "nearly at {scale}");
+        }
+    }
+
+    #[test]
+    fn degenerate_segments() {
+        let a = segment((0.0, 0.0), (2.0, 0.0), 1.0);
+        let point = segment((1.0, 0.0), (1.0, 0.0), 1.0);
+        assert_eq!(
+            a.intersect(&point),
+            SegmentIntersection::Point(Vec2::new(1.0, 0.0))
+        );
+        assert_eq!(point.closest_t(Vec2::new(5.0, 5.0)), 0.0);
+
+        let off = segment((1.0, 0.001), (1.0, 0.001), 1.0);
+        assert_eq!(a.intersect(&off), SegmentIntersection::None);
+
+        // Short segments are still segments.
+        let short = segment((0.0, 0.0), (1e-4, 0.0), 1.0);
+        assert!((short.closest_t(Vec2::new(5e-5, 1.0)) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn closest_points() {
+        let a = segment((0.0, 0.0), (2.0, 0.0), 1.0);
+        let b = segment((3.0, 1.0), (3.0, 2.0), 1.0);
+        let (on_a, on_b) = a.closest_points(&b);
+        assert_eq!(on_a, Vec2::new(2.0, 0.0));
+        assert_eq!(on_b, Vec2::new(3.0, 1.0));
+        assert!((a.distance_to_point(Vec2::new(1.0, 3.0)) - 3.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::vec::Vec3;
+use serde::Deserialize;
+
+const EPSILON: f32 = 1e-7;
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Segment3 {
+    start: Vec3,
+    end: Vec3,
+}
+
+impl Segment3 {
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> Vec3 {
+        self.start
+    }
+
+    pub fn end(&self) -> Vec3 {
+        self.end
+    }
+
+    /// Unnormalized, from `start` to `end`.
+    pub fn direction(&self) -> Vec3 {
+        self.end - self.start
+    }
+
+    pub fn length(&self) -> f32 {
+        self.direction().magnitude()
+    }
+
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.start + self.direction() * t
+    }
+
+    /// Parameter in `[0, 1]` of the point on the segment closest to `point`.
+    pub fn closest_t(&self, point: Vec3) -> f32 {
+        let direction = self.direction();
+        let length_squared = direction.magnitude_squared();
+        if length_squared <= EPSILON {
+            return 0.0;
+        }
+        ((point - self.start).dot(&direction) / length_squared).clamp(0.0, 1.0)
+    }
+
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        self.at(self.closest_t(point))
+    }
+
+    pub fn distance_squared_to_point(&self, point: Vec3) -> f32 {
+        (self.closest_point(point) - point).magnitude_squared()
+    }
+
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.distance_squared_to_point(point).sqrt()
+    }
+
+    /// Closest pair of points, one on each segment (Ericson, RTCD 5.1.9).
+    pub fn closest_points(&self, rhs: &Self) -> (Vec3, Vec3) {
+        let d1 = self.direction();
+        let d2 = rhs.direction();
+        let r = self.start - rhs.start;
+        let a = d1.magnitude_squared();
+        let e = d2.magnitude_squared();
+        let f = d2.dot(&r);
+
+        let (s, t) = if a <= EPSILON && e <= EPSILON {
+            (0.0, 0.0)
+        } else if a <= EPSILON {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(&r);
+            if e <= EPSILON {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                let s = if denom != 0.0 {
+                    ((b * f - c * e) / denom).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                let t = (b * s + f) / e;
+                if t < 0.0 {
+                    ((-c / a).clamp(0.0, 1.0), 0.0)
+                } else if t > 1.0 {
+                    (((b - c) / a).clamp(0.0, 1.0), 1.0)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.at(s), rhs.at(t))
+    }
+
+    pub fn distance_squared(&self, rhs: &Self) -> f32 {
+        let (a, b) = self.closest_points(rhs);
+        (a - b).magnitude_squared()
+    }
+}
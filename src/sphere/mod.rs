@@ -0,0 +1,400 @@
+use crate::{aabb::Aabb3, mat::Mat4, vec::Vec3};
+use serde::Deserialize;
+
+const EPSILON: f32 = 1e-6;
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Sphere {
+    center: Vec3,
+    radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn center(&self) -> Vec3 {
+        self.center
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        4.0 * std::f32::consts::PI * self.radius * self.radius
+    }
+
+    pub fn volume(&self) -> f32 {
+        4.0 / 3.0 * std::f32::consts::PI * self.radius * self.radius * self.radius
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        (point - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let radii = self.radius + rhs.radius;
+        (rhs.center - self.center).magnitude_squared() <= radii * radii
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb3) -> bool {
+        (aabb.closest_point(self.center) - self.center).magnitude_squared()
+            <= self.radius * self.radius
+    }
+
+    /// Smallest sphere enclosing both spheres.
+    pub fn union(&self, rhs: &Self) -> Self {
+        let offset = rhs.center - self.center;
+        let distance = offset.magnitude();
+        if distance + rhs.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= rhs.radius {
+            return *rhs;
+        }
+
+        let radius = (distance + self.radius + rhs.radius) * 0.5;
+        let center = self.center + offset * ((radius - self.radius) / distance);
+        Self::new(center, radius)
+    }
+
+    pub fn include_point(&self, point: Vec3) -> Self {
+        self.union(&Self::new(point, 0.0))
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb3 {
+        Aabb3::from_center_extents(
+            self.center,
+            Vec3::new(self.radius, self.radius, self.radius),
+        )
+    }
+
+    /// Bounds of the transformed sphere; non-uniform scale uses the largest axis.
+    pub fn transform(&self, mat: &Mat4) -> Self {
+        let scale = (0..3)
+            .map(|axis| mat.col(axis).xyz().magnitude())
+            .fold(0.0, f32::max);
+        Self::new(mat.transform_point(self.center), self.radius * scale)
+    }
+
+    /// Ritter's approximate bounding sphere, typically within a few percent
+    /// of the minimal one.
+    pub fn from_points_ritter(points: &[Vec3]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vec3| {
+            points.iter().copied().fold(from, |farthest, point| {
+                if point.distance(from) > farthest.distance(from) {
+                    point
+                } else {
+                    farthest
+                }
+            })
+        };
+
+        let y = farthest_from(first);
+        let z = farthest_from(y);
+        let mut sphere = Self::new((y + z) * 0.5, y.distance(z) * 0.5);
+
+        for point in points {
+            let distance = point.distance(sphere.center);
+            if distance > sphere.radius {
+                let radius = (sphere.radius + distance) * 0.5;
+                let center = sphere.center
+                    + (*point - sphere.center) * ((radius - sphere.radius) / distance);
+                sphere = Self::new(center, radius);
+            }
+        }
+
+        Some(sphere)
+    }
+
+    /// Welzl's minimal enclosing sphere, using the move-to-front variant so
+    /// the recursion depth is bounded by the four support points.
+    pub fn from_points_welzl(points: &[Vec3]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let mut points = points.to_vec();
+        let len = points.len();
+        let mut support = Vec::with_capacity(4);
+        Some(Self::welzl(&mut points, len, &mut support))
+    }
+
+    fn welzl(points: &mut [Vec3], len: usize, support: &mut Vec<Vec3>) -> Self {
+        let mut sphere = Self::from_support(support);
+        if support.len() == 4 {
+            return sphere;
+        }
+
+        for i in 0..len {
+            let point = points[i];
+            if !sphere.contains_with_tolerance(point) {
+                support.push(point);
+                sphere = Self::welzl(points, i, support);
+                support.pop();
+                points[..=i].rotate_right(1);
+            }
+        }
+
+        sphere
+    }
+
+    fn contains_with_tolerance(&self, point: Vec3) -> bool {
+        point.distance(self.center) <= self.radius + EPSILON * self.radius.max(1.0)
+    }
+
+    /// Smallest sphere with every support point on its surface.
+    fn from_support(support: &[Vec3]) -> Self {
+        match *support {
+            [] => Self::new(Vec3::zero(), -1.0),
+            [a] => Self::new(a, 0.0),
+            [a, b] => Self::new((a + b) * 0.5, a.distance(b) * 0.5),
+            [a, b, c] => {
+                let ab = b - a;
+                let ac = c - a;
+                let normal = ab.cross(&ac);
+                let denom = 2.0 * normal.magnitude_squared();
+                // Relative to the edge lengths, so the check is scale-free.
+                if denom <= 2.0 * EPSILON * ab.magnitude_squared() * ac.magnitude_squared() {
+                    return Self::enclosing_subset(support);
+                }
+
+                let offset = (ac * ab.magnitude_squared() - ab * ac.magnitude_squared())
+                    .cross(&normal)
+                    / denom;
+                Self::new(a + offset, offset.magnitude())
+            }
+            [a, b, c, d] => {
+                let ab = b - a;
+                let ac = c - a;
+                let ad = d - a;
+                let denom = 2.0 * ab.dot(&ac.cross(&ad));
+                if denom.abs() <= 2.0 * EPSILON * ab.magnitude() * ac.magnitude() * ad.magnitude() {
+                    return Self::enclosing_subset(support);
+                }
+
+                let offset = (ac.cross(&ad) * ab.magnitude_squared()
+                    + ad.cross(&ab) * ac.magnitude_squared()
+                    + ab.cross(&ac) * ad.magnitude_squared())
+                    / denom;
+                Self::new(a + offset, offset.magnitude())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Fallback for degenerate support sets: the smallest sphere through a
+    /// subset of the points that still encloses all of them.
+    fn enclosing_subset(support: &[Vec3]) -> Self {
+        let mut best = Self::new(Vec3::zero(), f32::INFINITY);
+        for skip in 0..support.len() {
+            let subset: Vec<Vec3> = support
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != skip)
+                .map(|(_, point)| *point)
+                .collect();
+            let sphere = Self::from_support(&subset);
+            if sphere.radius < best.radius
+                && support
+                    .iter()
+                    .all(|point| sphere.contains_with_tolerance(*point))
+            {
+                best = sphere;
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{Pcg32, RandomSource};
+
+    fn assert_encloses(sphere: &Sphere, points: &[Vec3]) {
+        for point in points {
+            assert!(
+                point.distance(sphere.center()) <= sphere.radius() * (1.0 + 1e-4) + 1e-5,
+                "{point:?} outside {sphere:?}"
+            );
+        }
+    }
+
+    fn assert_sphere(sphere: Sphere, center: Vec3, radius: f32) {
+        assert!(
+            sphere.center().distance(center) < 1e-4,
+            "center {:?}",
+            sphere.center()
+        );
+        assert!(
+            (sphere.radius() - radius).abs() < 1e-4,
+            "radius {}",
+            sphere.radius()
+        );
+    }
+
+    /// Smallest sphere through any subset of up to four points that encloses them all.
+    fn brute_force(points: &[Vec3]) -> f32 {
+        let len = points.len();
+        let mut best = f32::INFINITY;
+        for a in 0..len {
+            for b in a..len {
+                for c in b..len {
+                    for d in c..len {
+                        let mut support = vec![points[a], points[b], points[c], points[d]];
+                        support.dedup_by(|x, y| x == y);
+                        for size in 1..=support.len() {
+                            let sphere = Sphere::from_support(&support[..size]);
+                            if points.iter().all(|p| sphere.contains_with_tolerance(*p)) {
+                                best = best.min(sphere.radius());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(Sphere::from_points_welzl(&[]), None);
+        assert_eq!(Sphere::from_points_ritter(&[]), None);
+    }
+
+    #[test]
+    fn welzl_known_configurations() {
+        let single = Vec3::new(1.0, 2.0, 3.0);
+        assert_sphere(Sphere::from_points_welzl(&[single]).unwrap(), single, 0.0);
+
+        let pair = [Vec3::new(-1.0, 0.0, 0.0), Vec3::new(3.0, 0.0, 0.0)];
+        assert_sphere(
+            Sphere::from_points_welzl(&pair).unwrap(),
+            Vec3::new(1.0, 0.0, 0.0),
+            2.0,
+        );
+
+        // Equilateral triangle of side 2: circumradius 2/√3.
+        let triangle = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(1.0, 3f32.sqrt(), 0.0),
+        ];
+        assert_sphere(
+            Sphere::from_points_welzl(&triangle).unwrap(),
+            Vec3::new(1.0, 3f32.sqrt() / 3.0, 0.0),
+            2.0 / 3f32.sqrt(),
+        );
+
+        // Obtuse triangle: the longest edge is the diameter, not the circumcircle.
+        let obtuse = [
+            Vec3::new(-2.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.5, 0.0),
+        ];
+        assert_sphere(
+            Sphere::from_points_welzl(&obtuse).unwrap(),
+            Vec3::zero(),
+            2.0,
+        );
+
+        let cube: Vec<Vec3> = (0..8)
+            .map(|i| {
+                Vec3::new(
+                    (i & 1) as f32 * 2.0 - 1.0,
+                    (i >> 1 & 1) as f32 * 2.0 - 1.0,
+                    (i >> 2 & 1) as f32 * 2.0 - 1.0,
+                )
+            })
+            .collect();
+        assert_sphere(
+            Sphere::from_points_welzl(&cube).unwrap(),
+            Vec3::zero(),
+            3f32.sqrt(),
+        );
+
+        let tetrahedron = [
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(-1.0, 1.0, -1.0),
+            Vec3::new(-1.0, -1.0, 1.0),
+        ];
+        assert_sphere(
+            Sphere::from_points_welzl(&tetrahedron).unwrap(),
+            Vec3::zero(),
+            3f32.sqrt(),
+        );
+
+        // Degeneracy checks must not depend on the scale of the input.
+        let small_triangle = triangle.map(|point| point * 0.005);
+        let sphere = Sphere::from_points_welzl(&small_triangle).unwrap();
+        assert!((sphere.radius() - 0.01 / 3f32.sqrt()).abs() < 1e-6);
+        let small_tetrahedron = tetrahedron.map(|point| point * 0.01);
+        let sphere = Sphere::from_points_welzl(&small_tetrahedron).unwrap();
+        assert!(sphere.center().magnitude() < 1e-6);
+        assert!((sphere.radius() - 0.01 * 3f32.sqrt()).abs() < 1e-6);
+        let large_tetrahedron = tetrahedron.map(|point| point * 1000.0);
+        let sphere = Sphere::from_points_welzl(&large_tetrahedron).unwrap();
+        assert!((sphere.radius() - 1000.0 * 3f32.sqrt()).abs() < 1e-1);
+    }
+
+    #[test]
+    fn welzl_axis_points_with_interior_noise() {
+        let mut rng = Pcg32::new(7);
+        let mut points: Vec<Vec3> = (0..200)
+            .map(|_| {
+                Vec3::new(
+                    rng.range(-0.5, 0.5),
+                    rng.range(-0.5, 0.5),
+                    rng.range(-0.5, 0.5),
+                )
+            })
+            .collect();
+        points.extend([
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+        ]);
+
+        let sphere = Sphere::from_points_welzl(&points).unwrap();
+        assert_encloses(&sphere, &points);
+        assert_sphere(sphere, Vec3::zero(), 1.0);
+    }
+
+    #[test]
+    fn welzl_is_minimal_and_ritter_encloses() {
+        let mut rng = Pcg32::new(11);
+        for _ in 0..50 {
+            let points: Vec<Vec3> = (0..8)
+                .map(|_| {
+                    Vec3::new(
+                        rng.range(-5.0, 5.0),
+                        rng.range(-5.0, 5.0),
+                        rng.range(-5.0, 5.0),
+                    )
+                })
+                .collect();
+
+            let welzl = Sphere::from_points_welzl(&points).unwrap();
+            assert_encloses(&welzl, &points);
+            let minimal = brute_force(&points);
+            assert!(
+                (welzl.radius() - minimal).abs() < 1e-3 * minimal.max(1.0),
+                "welzl {} vs brute force {minimal}",
+                welzl.radius()
+            );
+
+            let ritter = Sphere::from_points_ritter(&points).unwrap();
+            assert_encloses(&ritter, &points);
+            assert!(ritter.radius() >= welzl.radius() - 1e-4);
+        }
+    }
+}
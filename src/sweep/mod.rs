@@ -0,0 +1,361 @@
+use crate::{aabb::Aabb3, plane::Plane, ray::Ray3, sphere::Sphere, triangle::Triangle3, vec::Vec3};
+
+const EPSILON: f32 = 1e-7;
+
+/// `time` is the fraction in `[0, 1]` of the motion covered before first
+/// contact, and `normal` points from the obstacle towards the moving shape.
+/// Shapes that already overlap report a time of zero.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Impact {
+    pub time: f32,
+    pub normal: Vec3,
+}
+
+/// `sphere` moves by `velocity` over the interval.
+pub fn sphere_plane(sphere: &Sphere, velocity: Vec3, plane: &Plane) -> Option<Impact> {
+    let distance = plane.signed_distance(sphere.center());
+    let normal = if distance >= 0.0 {
+        plane.normal()
+    } else {
+        plane.normal().negate()
+    };
+
+    if distance.abs() <= sphere.radius() {
+        return Some(Impact { time: 0.0, normal });
+    }
+
+    let denom = plane.normal().dot(&velocity);
+    if denom * distance >= 0.0 {
+        return None;
+    }
+
+    let radius = if distance > 0.0 {
+        sphere.radius()
+    } else {
+        -sphere.radius()
+    };
+    let time = (radius - distance) / denom;
+    (time <= 1.0).then_some(Impact { time, normal })
+}
+
+/// Both spheres move over the interval; the normal points from `b` towards `a`.
+pub fn sphere_sphere(a: &Sphere, a_velocity: Vec3, b: &Sphere, b_velocity: Vec3) -> Option<Impact> {
+    let offset = a.center() - b.center();
+    let velocity = a_velocity - b_velocity;
+    let radius = a.radius() + b.radius();
+
+    let c = offset.magnitude_squared() - radius * radius;
+    let time = if c <= 0.0 {
+        0.0
+    } else {
+        let a = velocity.magnitude_squared();
+        let b = velocity.dot(&offset);
+        if a < EPSILON || b >= 0.0 {
+            return None;
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let time = (-b - discriminant.sqrt()) / a;
+        if time > 1.0 {
+            return None;
+        }
+        time
+    };
+
+    let separation = offset + velocity * time;
+    let normal = if separation.magnitude_squared() > EPSILON {
+        separation.normalized()
+    } else {
+        velocity.negate().normalized()
+    };
+    Some(Impact { time, normal })
+}
+
+/// Both boxes move over the interval; the normal points from `b` towards `a`.
+pub fn aabb_aabb(a: &Aabb3, a_velocity: Vec3, b: &Aabb3, b_velocity: Vec3) -> Option<Impact> {
+    let velocity = a_velocity - b_velocity;
+
+    if let Some(overlap) = a.intersection(b) {
+        // Push out along the axis of least penetration.
+        let size = overlap.size();
+        let axis = (0..3)
+            .min_by(|i, j| size.idx(*i).total_cmp(&size.idx(*j)))
+            .unwrap_or(0);
+        let mut normal = [0.0; 3];
+        normal[axis] = if a.center().idx(axis) >= b.center().idx(axis) {
+            1.0
+        } else {
+            -1.0
+        };
+        return Some(Impact {
+            time: 0.0,
+            normal: normal.into(),
+        });
+    }
+
+    // Shrink `a` to a point and grow `b` by the same amount, then cast a ray.
+    let expanded = Aabb3::new(b.min() - a.extents(), b.max() + a.extents());
+    let hit = Ray3::new(a.center(), velocity).intersect_aabb(&expanded)?;
+    (hit.distance <= 1.0).then_some(Impact {
+        time: hit.distance,
+        normal: hit.normal,
+    })
+}
+
+/// `sphere` moves by `velocity` over the interval. Tests the face first, then
+/// the edges as cylinders and the vertices as spheres.
+pub fn sphere_triangle(sphere: &Sphere, velocity: Vec3, triangle: &Triangle3) -> Option<Impact> {
+    let center = sphere.center();
+    let radius = sphere.radius();
+
+    let closest = triangle.closest_point(center);
+    if (closest - center).magnitude_squared() <= radius * radius {
+        let offset = center - closest;
+        let normal = if offset.magnitude_squared() > EPSILON {
+            offset.normalized()
+        } else {
+            triangle.normal()
+        };
+        return Some(Impact { time: 0.0, normal });
+    }
+
+    let plane = Plane::from_points(triangle.a(), triangle.b(), triangle.c());
+    if let Some(impact) = sphere_plane(sphere, velocity, &plane) {
+        let contact = center + velocity * impact.time - impact.normal * radius;
+        let weights = triangle.barycentric(contact);
+        if weights.x() >= 0.0 && weights.y() >= 0.0 && weights.z() >= 0.0 {
+            return Some(impact);
+        }
+    }
+
+    let ray = Ray3::new(center, velocity);
+    let mut best: Option<Impact> = None;
+    let mut consider = |impact: Impact| {
+        if impact.time <= 1.0 && impact.time < best.map_or(f32::INFINITY, |best| best.time) {
+            best = Some(impact);
+        }
+    };
+
+    for vertex in triangle.vertices() {
+        if let Some(hit) = ray.intersect_sphere(&Sphere::new(vertex, radius)) {
+            consider(Impact {
+                time: hit.distance,
+                normal: hit.normal,
+            });
+        }
+    }
+
+    let vertices = triangle.vertices();
+    for i in 0..3 {
+        let (start, end) = (vertices[i], vertices[(i + 1) % 3]);
+        if let Some(impact) = sweep_edge(center, velocity, radius, start, end) {
+            consider(impact);
+        }
+    }
+
+    best
+}
+
+/// Ray against the cylinder of `radius` around the edge, limited to the
+/// edge's length.
+fn sweep_edge(center: Vec3, velocity: Vec3, radius: f32, start: Vec3, end: Vec3) -> Option<Impact> {
+    let edge = end - start;
+    let edge_length_squared = edge.magnitude_squared();
+    if edge_length_squared < EPSILON {
+        return None;
+    }
+
+    let perpendicular = |vec: Vec3| vec - edge * (vec.dot(&edge) / edge_length_squared);
+    let offset = perpendicular(center - start);
+    let direction = perpendicular(velocity);
+
+    let a = direction.magnitude_squared();
+    let b = offset.dot(&direction);
+    let c = offset.magnitude_squared() - radius * radius;
+    if a < EPSILON || b >= 0.0 {
+        return None;
+    }
+
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let time = (-b - discriminant.sqrt()) / a;
+    let moved = center + velocity * time;
+    let along = (moved - start).dot(&edge) / edge_length_squared;
+    if !(0.0..=1.0).contains(&along) || time < 0.0 {
+        return None;
+    }
+
+    Some(Impact {
+        time,
+        normal: (moved - (start + edge * along)).normalized(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_impact(impact: Option<Impact>, time: f32, normal: Vec3) {
+        let impact = impact.expect("expected an impact");
+        assert!((impact.time - time).abs() < 1e-5, "time {}", impact.time);
+        assert!(
+            (impact.normal - normal).magnitude() < 1e-4,
+            "normal {:?}",
+            impact.normal
+        );
+    }
+
+    fn x() -> Vec3 {
+        Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn sphere_plane_cases() {
+        let plane = Plane::new(x(), 0.0);
+        let sphere = Sphere::new(Vec3::new(5.0, 0.0, 0.0), 1.0);
+
+        // Moves straight through the plane within one step.
+        assert_impact(
+            sphere_plane(&sphere, Vec3::new(-20.0, 0.0, 0.0), &plane),
+            0.2,
+            x(),
+        );
+        assert_impact(
+            sphere_plane(
+                &Sphere::new(Vec3::new(0.5, 0.0, 0.0), 1.0),
+                Vec3::zero(),
+                &plane,
+            ),
+            0.0,
+            x(),
+        );
+        assert_eq!(
+            sphere_plane(&sphere, Vec3::new(-2.0, 0.0, 0.0), &plane),
+            None
+        );
+        assert_eq!(
+            sphere_plane(&sphere, Vec3::new(10.0, 0.0, 0.0), &plane),
+            None
+        );
+        assert_eq!(
+            sphere_plane(&sphere, Vec3::new(0.0, 10.0, 0.0), &plane),
+            None
+        );
+    }
+
+    #[test]
+    fn sphere_sphere_cases() {
+        let a = Sphere::new(Vec3::zero(), 1.0);
+        let b = Sphere::new(Vec3::new(10.0, 0.0, 0.0), 1.0);
+
+        // Tunnelling: `a` would end up well past `b`.
+        assert_impact(
+            sphere_sphere(&a, Vec3::new(20.0, 0.0, 0.0), &b, Vec3::zero()),
+            0.4,
+            x().negate(),
+        );
+        // Both moving towards each other.
+        assert_impact(
+            sphere_sphere(&a, Vec3::new(4.0, 0.0, 0.0), &b, Vec3::new(-4.0, 0.0, 0.0)),
+            1.0,
+            x().negate(),
+        );
+
+        let overlapping = Sphere::new(Vec3::new(1.5, 0.0, 0.0), 1.0);
+        assert_impact(
+            sphere_sphere(&a, Vec3::zero(), &overlapping, Vec3::zero()),
+            0.0,
+            x().negate(),
+        );
+
+        assert_eq!(
+            sphere_sphere(&a, Vec3::new(5.0, 0.0, 0.0), &b, Vec3::zero()),
+            None
+        );
+        assert_eq!(
+            sphere_sphere(&a, Vec3::new(20.0, 10.0, 0.0), &b, Vec3::zero()),
+            None
+        );
+        assert_eq!(
+            sphere_sphere(&a, Vec3::new(-20.0, 0.0, 0.0), &b, Vec3::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn aabb_aabb_cases() {
+        let unit = Vec3::new(0.5, 0.5, 0.5);
+        let a = Aabb3::from_center_extents(Vec3::zero(), unit);
+        let wall = Aabb3::new(Vec3::new(4.0, -5.0, -5.0), Vec3::new(4.1, 5.0, 5.0));
+
+        assert_impact(
+            aabb_aabb(&a, Vec3::new(35.0, 0.0, 0.0), &wall, Vec3::zero()),
+            0.1,
+            x().negate(),
+        );
+
+        let overlapping = Aabb3::from_center_extents(Vec3::new(0.8, 0.1, 0.0), unit);
+        assert_impact(
+            aabb_aabb(&a, Vec3::zero(), &overlapping, Vec3::zero()),
+            0.0,
+            x().negate(),
+        );
+
+        assert_eq!(
+            aabb_aabb(&a, Vec3::new(3.0, 0.0, 0.0), &wall, Vec3::zero()),
+            None
+        );
+        assert_eq!(
+            aabb_aabb(&a, Vec3::new(0.0, 35.0, 0.0), &wall, Vec3::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn sphere_triangle_cases() {
+        let triangle = Triangle3::new(
+            Vec3::new(0.0, -2.0, -2.0),
+            Vec3::new(0.0, 2.0, -2.0),
+            Vec3::new(0.0, 0.0, 2.0),
+        );
+        let sphere = Sphere::new(Vec3::new(-5.0, 0.0, 0.0), 1.0);
+
+        // Face hit while passing straight through.
+        assert_impact(
+            sphere_triangle(&sphere, Vec3::new(20.0, 0.0, 0.0), &triangle),
+            0.2,
+            x().negate(),
+        );
+
+        // Edge hit: the centre passes just below the bottom edge.
+        let below = Sphere::new(Vec3::new(-5.0, 0.0, -2.5), 1.0);
+        let impact = sphere_triangle(&below, Vec3::new(10.0, 0.0, 0.0), &triangle).unwrap();
+        let contact = below.center() + Vec3::new(10.0, 0.0, 0.0) * impact.time;
+        assert!((triangle.closest_point(contact) - contact).magnitude() - 1.0 < 1e-4);
+        assert!(impact.time > 0.0 && impact.time < 0.5);
+
+        let touching = Sphere::new(Vec3::new(0.5, 0.0, 0.0), 1.0);
+        assert_impact(
+            sphere_triangle(&touching, Vec3::zero(), &triangle),
+            0.0,
+            x(),
+        );
+
+        let beside = Sphere::new(Vec3::new(-5.0, 5.0, 0.0), 1.0);
+        assert_eq!(
+            sphere_triangle(&beside, Vec3::new(20.0, 0.0, 0.0), &triangle),
+            None
+        );
+        assert_eq!(
+            sphere_triangle(&sphere, Vec3::new(2.0, 0.0, 0.0), &triangle),
+            None
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{
+    affine::Affine3,
+    lerp::Lerp,
+    mat::{Mat3, Mat4},
+    quat::Quat,
+    vec::Vec3,
+};
+use serde::Deserialize;
+
+/// Translation, rotation and scale, applied to points in the order scale,
+/// rotate, translate. Right-handed, with -Z as forward and +Y as up.
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq)]
+pub struct Transform {
+    translation: Vec3,
+    rotation: Quat,
+    scale: Vec3,
+}
+
+impl Transform {
+    pub fn new(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Vec3::zero(), Quat::identity(), Vec3::new(1.0, 1.0, 1.0))
+    }
+
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Quat) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// Decomposes an affine matrix. Shear is lost, and a reflection becomes
+    /// a negative x scale.
+    pub fn from_mat4(mat: &Mat4) -> Self {
+        let (translation, rotation, scale) = mat.decompose();
+        Self::new(translation, rotation, scale)
+    }
+
+    /// At `eye`, facing `target`.
+    pub fn from_look_at(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let mut transform = Self::from_translation(eye);
+        transform.look_at(target, up);
+        transform
+    }
+
+    pub fn translation(&self) -> Vec3 {
+        self.translation
+    }
+
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    pub fn scale(&self) -> Vec3 {
+        self.scale
+    }
+
+    pub fn set_translation(&mut self, translation: Vec3) {
+        self.translation = translation;
+    }
+
+    pub fn set_rotation(&mut self, rotation: Quat) {
+        self.rotation = rotation;
+    }
+
+    pub fn set_scale(&mut self, scale: Vec3) {
+        self.scale = scale;
+    }
+
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_trs(self.translation, self.rotation, self.scale)
+    }
+
+    pub fn to_affine(&self) -> Affine3 {
+        Affine3::from_trs(self.translation, self.rotation, self.scale)
+    }
+
+    pub fn forward(&self) -> Vec3 {
+        self.rotation.rotate_vector(Vec3::new(0.0, 0.0, -1.0))
+    }
+
+    pub fn right(&self) -> Vec3 {
+        self.rotation.rotate_vector(Vec3::new(1.0, 0.0, 0.0))
+    }
+
+    pub fn up(&self) -> Vec3 {
+        self.rotation.rotate_vector(Vec3::new(0.0, 1.0, 0.0))
+    }
+
+    /// Turns to face `target`, keeping `right` perpendicular to `up`. Does
+    /// nothing if `target` is at the translation or straight along `up`.
+    pub fn look_at(&mut self, target: Vec3, up: Vec3) {
+        let back = self.translation - target;
+        let right = up.cross(&back);
+        if back.magnitude_squared() == 0.0 || right.magnitude_squared() == 0.0 {
+            return;
+        }
+
+        let back = back.normalized();
+        let right = right.normalized();
+        let up = back.cross(&right);
+        self.rotation = Quat::from_mat3(&Mat3::from_cols(right, up, back));
+    }
+
+    pub fn transform_point(&self, point: Vec3) -> Vec3 {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Scales and rotates, but does not translate.
+    pub fn transform_vector(&self, vec: Vec3) -> Vec3 {
+        self.rotation.rotate_vector(Vec3::new(
+            vec.x() * self.scale.x(),
+            vec.y() * self.scale.y(),
+            vec.z() * self.scale.z(),
+        ))
+    }
+
+    /// `child` placed in the space of `self`, so that the result transforms
+    /// points as `self` after `child`. Exact unless `self` has non-uniform
+    /// scale and `child` is rotated, which would need shear.
+    pub fn mul_transform(&self, child: &Self) -> Self {
+        Self::new(
+            self.transform_point(child.translation),
+            (self.rotation * child.rotation).normalized(),
+            Vec3::new(
+                self.scale.x() * child.scale.x(),
+                self.scale.y() * child.scale.y(),
+                self.scale.z() * child.scale.z(),
+            ),
+        )
+    }
+
+    /// Exact for uniform scale; with non-uniform scale and a rotation, the
+    /// true inverse needs shear and this is only an approximation.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scale = Vec3::new(
+            1.0 / self.scale.x(),
+            1.0 / self.scale.y(),
+            1.0 / self.scale.z(),
+        );
+        let translation = rotation.rotate_vector(self.translation.negate());
+        Self::new(
+            Vec3::new(
+                translation.x() * scale.x(),
+                translation.y() * scale.y(),
+                translation.z() * scale.z(),
+            ),
+            rotation,
+            scale,
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl std::ops::Mul<Transform> for Transform {
+    type Output = Self;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        self.mul_transform(&rhs)
+    }
+}
+
+/// Translation and scale linearly, rotation spherically.
+impl Lerp for Transform {
+    fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        Self::new(
+            self.translation.lerp(&rhs.translation, t),
+            self.rotation.lerp(&rhs.rotation, t),
+            self.scale.lerp(&rhs.scale, t),
+        )
+    }
+}
+
+impl From<Transform> for Mat4 {
+    fn from(transform: Transform) -> Self {
+        transform.to_mat4()
+    }
+}
+
+impl From<Transform> for Affine3 {
+    fn from(transform: Transform) -> Self {
+        transform.to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec(actual: Vec3, expected: Vec3) {
+        assert!(
+            (actual - expected).magnitude() < 1e-4,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn sample() -> (Transform, Transform) {
+        let parent = Transform::new(
+            Vec3::new(1.0, -2.0, 3.0),
+            Quat::from_axis_angle(Vec3::new(1.0, 1.0, 0.0).normalized(), 0.8),
+            Vec3::new(2.0, 2.0, 2.0),
+        );
+        let child = Transform::new(
+            Vec3::new(-0.5, 4.0, 1.5),
+            Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), -1.1),
+            Vec3::new(0.5, 1.5, 3.0),
+        );
+        (parent, child)
+    }
+
+    fn points() -> [Vec3; 3] {
+        [
+            Vec3::zero(),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, 2.0),
+        ]
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let (parent, _) = sample();
+        // Non-uniform scale is exact as long as there is no rotation.
+        let stretched = Transform::new(
+            Vec3::new(0.5, 0.0, -7.0),
+            Quat::identity(),
+            Vec3::new(0.25, 4.0, -2.0),
+        );
+        for transform in [parent, stretched] {
+            let inverse = transform.inverse();
+            for point in points() {
+                assert_vec(
+                    inverse.transform_point(transform.transform_point(point)),
+                    point,
+                );
+                assert_vec(
+                    transform.transform_point(inverse.transform_point(point)),
+                    point,
+                );
+            }
+        }
+
+        let identity = parent.mul_transform(&parent.inverse());
+        for point in points() {
+            assert_vec(identity.transform_point(point), point);
+        }
+    }
+
+    #[test]
+    fn mul_transform_applies_child_first() {
+        let (parent, child) = sample();
+        let combined = parent.mul_transform(&child);
+        let matrix = parent.to_mat4() * child.to_mat4();
+        for point in points() {
+            let expected = parent.transform_point(child.transform_point(point));
+            assert_vec(combined.transform_point(point), expected);
+            assert_vec(matrix.transform_point(point), expected);
+            assert_vec((parent * child).transform_point(point), expected);
+        }
+    }
+
+    #[test]
+    fn look_at_faces_target() {
+        let eye = Vec3::new(3.0, 2.0, -1.0);
+        let target = Vec3::new(-1.0, 0.5, 4.0);
+        let up = Vec3::new(0.0, 1.0, 0.0);
+        let transform = Transform::from_look_at(eye, target, up);
+
+        assert_vec(transform.translation(), eye);
+        assert_vec(transform.forward(), (target - eye).normalized());
+        assert!(transform.right().dot(&up).abs() < 1e-5);
+        assert!(transform.up().dot(&up) > 0.0);
+        assert_vec(
+            transform.right().cross(&transform.up()),
+            transform.forward().negate(),
+        );
+
+        // Looking straight along `up` leaves the rotation untouched.
+        let mut transform = Transform::from_translation(Vec3::zero());
+        transform.look_at(Vec3::new(0.0, 5.0, 0.0), up);
+        assert_eq!(transform.rotation(), Quat::identity());
+    }
+}
@@ -0,0 +1,7 @@
+mod triangle2;
+mod triangle3;
+
+pub use self::{
+    triangle2::{Triangle2, Winding},
+    triangle3::Triangle3,
+};
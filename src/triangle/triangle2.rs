@@ -0,0 +1,96 @@
+use crate::{
+    point::Point2D,
+    vec::{Vec2, Vec3},
+};
+use serde::Deserialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+    Degenerate,
+}
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Triangle2 {
+    a: Vec2,
+    b: Vec2,
+    c: Vec2,
+}
+
+impl Triangle2 {
+    pub fn new(a: Vec2, b: Vec2, c: Vec2) -> Self {
+        Self { a, b, c }
+    }
+
+    pub fn a(&self) -> Vec2 {
+        self.a
+    }
+
+    pub fn b(&self) -> Vec2 {
+        self.b
+    }
+
+    pub fn c(&self) -> Vec2 {
+        self.c
+    }
+
+    pub fn vertices(&self) -> [Vec2; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Positive for counter-clockwise triangles.
+    pub fn signed_area(&self) -> f32 {
+        (self.b - self.a).cross(&(self.c - self.a)) * 0.5
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    pub fn centroid(&self) -> Vec2 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    pub fn winding(&self) -> Winding {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Winding::CounterClockwise
+        } else if area < 0.0 {
+            Winding::Clockwise
+        } else {
+            Winding::Degenerate
+        }
+    }
+
+    pub fn reversed(&self) -> Self {
+        Self::new(self.a, self.c, self.b)
+    }
+
+    /// Weights of `a`, `b` and `c` that reproduce `point`.
+    pub fn barycentric(&self, point: Vec2) -> Vec3 {
+        let area = (self.b - self.a).cross(&(self.c - self.a));
+        let u = (self.b - point).cross(&(self.c - point)) / area;
+        let v = (self.c - point).cross(&(self.a - point)) / area;
+        Vec3::new(u, v, 1.0 - u - v)
+    }
+
+    /// Points on an edge count as inside. Works for either winding.
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let d0 = (self.b - self.a).cross(&(point - self.a));
+        let d1 = (self.c - self.b).cross(&(point - self.b));
+        let d2 = (self.a - self.c).cross(&(point - self.c));
+
+        let has_negative = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+        let has_positive = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+        !(has_negative && has_positive)
+    }
+}
+
+impl From<[Point2D<f32>; 3]> for Triangle2 {
+    fn from(points: [Point2D<f32>; 3]) -> Self {
+        let [a, b, c] = points.map(|point| Vec2::new(point.x(), point.y()));
+        Self::new(a, b, c)
+    }
+}
@@ -0,0 +1,404 @@
+use super::Triangle2;
+use crate::{
+    aabb::Aabb3,
+    segment::Segment2,
+    vec::{Vec2, Vec3},
+};
+use serde::Deserialize;
+
+const EPSILON: f32 = 1e-6;
+
+#[repr(C)]
+#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq)]
+pub struct Triangle3 {
+    a: Vec3,
+    b: Vec3,
+    c: Vec3,
+}
+
+impl Triangle3 {
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self { a, b, c }
+    }
+
+    pub fn a(&self) -> Vec3 {
+        self.a
+    }
+
+    pub fn b(&self) -> Vec3 {
+        self.b
+    }
+
+    pub fn c(&self) -> Vec3 {
+        self.c
+    }
+
+    pub fn vertices(&self) -> [Vec3; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Twice the area, perpendicular to the triangle following the
+    /// right-hand rule over `a`, `b`, `c`.
+    pub fn scaled_normal(&self) -> Vec3 {
+        (self.b - self.a).cross(&(self.c - self.a))
+    }
+
+    pub fn normal(&self) -> Vec3 {
+        self.scaled_normal().normalized()
+    }
+
+    pub fn area(&self) -> f32 {
+        self.scaled_normal().magnitude() * 0.5
+    }
+
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    pub fn bounding_aabb(&self) -> Aabb3 {
+        Aabb3::new(
+            self.a.min(&self.b).min(&self.c),
+            self.a.max(&self.b).max(&self.c),
+        )
+    }
+
+    /// Weights of `a`, `b` and `c` for `point` projected onto the triangle's plane.
+    pub fn barycentric(&self, point: Vec3) -> Vec3 {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = point - self.a;
+
+        let d00 = v0.dot(&v0);
+        let d01 = v0.dot(&v1);
+        let d11 = v1.dot(&v1);
+        let d20 = v2.dot(&v0);
+        let d21 = v2.dot(&v1);
+        let denom = d00 * d11 - d01 * d01;
+
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Vec3::new(1.0 - v - w, v, w)
+    }
+
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let weights = self.closest_barycentric(point);
+        self.a * weights.x() + self.b * weights.y() + self.c * weights.z()
+    }
+
+    /// Barycentric weights of the closest point on the triangle to `point`,
+    /// using Ericson's Voronoi region method (RTCD 5.1.5).
+    pub fn closest_barycentric(&self, point: Vec3) -> Vec3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return Vec3::new(1.0, 0.0, 0.0);
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return Vec3::new(0.0, 1.0, 0.0);
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            let v = d1 / (d1 - d3);
+            return Vec3::new(1.0 - v, v, 0.0);
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return Vec3::new(0.0, 0.0, 1.0);
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            let w = d2 / (d2 - d6);
+            return Vec3::new(1.0 - w, 0.0, w);
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return Vec3::new(0.0, 1.0 - w, w);
+        }
+
+        let denom = 1.0 / (va + vb + vc);
+        let v = vb * denom;
+        let w = vc * denom;
+        Vec3::new(1.0 - v - w, v, w)
+    }
+
+    /// True when `point` lies on the triangle, within a small tolerance of its plane.
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        (self.closest_point(point) - point).magnitude_squared() <= EPSILON * EPSILON
+    }
+
+    /// Drops the axis along which the triangle's normal is largest.
+    fn project(&self, point: Vec3) -> Vec2 {
+        let normal = self.scaled_normal();
+        let (x, y, z) = (normal.x().abs(), normal.y().abs(), normal.z().abs());
+        if x >= y && x >= z {
+            Vec2::new(point.y(), point.z())
+        } else if y >= z {
+            Vec2::new(point.z(), point.x())
+        } else {
+            Vec2::new(point.x(), point.y())
+        }
+    }
+
+    fn projected(&self, onto: &Self) -> Triangle2 {
+        Triangle2::new(
+            onto.project(self.a),
+            onto.project(self.b),
+            onto.project(self.c),
+        )
+    }
+
+    /// Möller's interval overlap test, falling back to a 2D test for coplanar triangles.
+    pub fn intersects(&self, rhs: &Self) -> bool {
+        let du = rhs.plane_distances(self);
+        if du[0] * du[1] > 0.0 && du[0] * du[2] > 0.0 {
+            return false;
+        }
+
+        let dv = self.plane_distances(rhs);
+        if dv[0] * dv[1] > 0.0 && dv[0] * dv[2] > 0.0 {
+            return false;
+        }
+
+        let n1 = self.scaled_normal();
+        let n2 = rhs.scaled_normal();
+        let direction = n1.cross(&n2);
+        let axis = (0..3)
+            .max_by(|i, j| direction.idx(*i).abs().total_cmp(&direction.idx(*j).abs()))
+            .unwrap_or(0);
+
+        let self_interval = self.vertices().map(|v| v.idx(axis));
+        let rhs_interval = rhs.vertices().map(|v| v.idx(axis));
+        match (
+            Self::interval(self_interval, du),
+            Self::interval(rhs_interval, dv),
+        ) {
+            (Some(a), Some(b)) => a.0 <= b.1 && b.0 <= a.1,
+            _ => self.intersects_coplanar(rhs),
+        }
+    }
+
+    /// Distances of `other`'s vertices from this triangle's plane. Distances
+    /// within a tolerance relative to the triangles' size snap to zero, so
+    /// the result does not depend on scale. A degenerate triangle has no
+    /// plane and reports zeros.
+    fn plane_distances(&self, other: &Self) -> [f32; 3] {
+        let normal = self.scaled_normal();
+        let magnitude = normal.magnitude();
+        if magnitude == 0.0 {
+            return [0.0; 3];
+        }
+
+        let normal = normal / magnitude;
+        let size = [self, other]
+            .iter()
+            .flat_map(|triangle| {
+                let [a, b, c] = triangle.vertices();
+                [b - a, c - b, a - c]
+            })
+            .map(|edge| edge.magnitude())
+            .fold(0.0, f32::max);
+        other.vertices().map(|vertex| {
+            let distance = normal.dot(&(vertex - self.a));
+            if distance.abs() < EPSILON * size {
+                0.0
+            } else {
+                distance
+            }
+        })
+    }
+
+    /// Range covered on the line of intersection, given each vertex's
+    /// projection and signed distance to the other triangle's plane.
+    fn interval(projection: [f32; 3], distance: [f32; 3]) -> Option<(f32, f32)> {
+        let [d0, d1, d2] = distance;
+        let alone = if d0 * d1 > 0.0 {
+            2
+        } else if d0 * d2 > 0.0 {
+            1
+        } else if d1 * d2 > 0.0 || d0 != 0.0 {
+            0
+        } else if d1 != 0.0 {
+            1
+        } else if d2 != 0.0 {
+            2
+        } else {
+            return None;
+        };
+
+        let (i, j) = ((alone + 1) % 3, (alone + 2) % 3);
+        let at = |other: usize| {
+            projection[alone]
+                + (projection[other] - projection[alone]) * distance[alone]
+                    / (distance[alone] - distance[other])
+        };
+        let (t0, t1) = (at(i), at(j));
+        Some((t0.min(t1), t0.max(t1)))
+    }
+
+    fn intersects_coplanar(&self, rhs: &Self) -> bool {
+        let a = self.projected(self);
+        let b = rhs.projected(self);
+
+        let a_vertices = a.vertices();
+        let b_vertices = b.vertices();
+        for i in 0..3 {
+            for j in 0..3 {
+                let a_edge = Segment2::new(a_vertices[i], a_vertices[(i + 1) % 3]);
+                let b_edge = Segment2::new(b_vertices[j], b_vertices[(j + 1) % 3]);
+                if a_edge.intersects(&b_edge) {
+                    return true;
+                }
+            }
+        }
+
+        a.contains_point(b.a()) || b.contains_point(a.a())
+    }
+
+    /// Separating axis test over the box axes, the triangle normal and the
+    /// nine edge cross products (Akenine-Möller).
+    pub fn intersects_aabb(&self, aabb: &Aabb3) -> bool {
+        let center = aabb.center();
+        let extents = aabb.extents();
+        let v = self.vertices().map(|vertex| vertex - center);
+        let edges = [v[1] - v[0], v[2] - v[1], v[0] - v[2]];
+
+        let separated = |axis: Vec3| {
+            let p = v.map(|vertex| vertex.dot(&axis));
+            let r = extents.x() * axis.x().abs()
+                + extents.y() * axis.y().abs()
+                + extents.z() * axis.z().abs();
+            p[0].min(p[1]).min(p[2]) > r || p[0].max(p[1]).max(p[2]) < -r
+        };
+
+        let box_axes = [
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+        for box_axis in box_axes {
+            for edge in edges {
+                if separated(box_axis.cross(&edge)) {
+                    return false;
+                }
+            }
+        }
+
+        if box_axes.into_iter().any(separated) {
+            return false;
+        }
+
+        !separated(edges[0].cross(&edges[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle(a: (f32, f32, f32), b: (f32, f32, f32), c: (f32, f32, f32)) -> Triangle3 {
+        Triangle3::new(
+            Vec3::new(a.0, a.1, a.2),
+            Vec3::new(b.0, b.1, b.2),
+            Vec3::new(c.0, c.1, c.2),
+        )
+    }
+
+    fn scaled(triangle: &Triangle3, scale: f32) -> Triangle3 {
+        Triangle3::new(triangle.a * scale, triangle.b * scale, triangle.c * scale)
+    }
+
+    #[test]
+    fn closest_point_regions() {
+        let t = triangle((0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0));
+        let cases = [
+            ((-1.0, -1.0, 1.0), (1.0, 0.0, 0.0)),
+            ((3.0, -1.0, 0.0), (0.0, 1.0, 0.0)),
+            ((-1.0, 3.0, 0.0), (0.0, 0.0, 1.0)),
+            ((1.0, -1.0, 5.0), (0.5, 0.5, 0.0)),
+            ((-1.0, 1.0, 0.0), (0.5, 0.0, 0.5)),
+            ((2.0, 2.0, -1.0), (0.0, 0.5, 0.5)),
+            ((0.5, 0.5, 3.0), (0.5, 0.25, 0.25)),
+        ];
+        for ((x, y, z), (wa, wb, wc)) in cases {
+            let point = Vec3::new(x, y, z);
+            let weights = t.closest_barycentric(point);
+            assert!(
+                (weights - Vec3::new(wa, wb, wc)).magnitude() < 1e-5,
+                "{point:?}: {weights:?}"
+            );
+            let closest = t.closest_point(point);
+            assert!((closest - (t.a * wa + t.b * wb + t.c * wc)).magnitude() < 1e-5);
+        }
+
+        assert!(t.contains_point(Vec3::new(0.5, 0.5, 0.0)));
+        assert!(!t.contains_point(Vec3::new(0.5, 0.5, 0.1)));
+    }
+
+    #[test]
+    fn intersects_triangle() {
+        let a = triangle((0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 2.0, 0.0));
+        let crossing = triangle((0.5, 0.5, -1.0), (0.5, 0.5, 1.0), (3.0, 0.5, 0.0));
+        let above = triangle((0.5, 0.5, 1.0), (1.5, 0.5, 1.0), (0.5, 1.5, 1.0));
+        let beside = triangle((3.0, 0.0, -1.0), (3.0, 0.0, 1.0), (4.0, 1.0, 0.0));
+        let touching = triangle((2.0, 0.0, 0.0), (3.0, 0.0, 1.0), (3.0, 0.0, -1.0));
+        let coplanar = triangle((1.0, 1.0, 0.0), (3.0, 1.0, 0.0), (1.0, 3.0, 0.0));
+        let coplanar_apart = triangle((2.0, 2.0, 0.0), (4.0, 2.0, 0.0), (2.0, 4.0, 0.0));
+
+        // Touching at a single vertex is exact only without rounding.
+        assert!(a.intersects(&touching));
+        assert!(touching.intersects(&a));
+
+        for scale in [1.0, 1e-4, 1e4] {
+            let a = scaled(&a, scale);
+            assert!(
+                a.intersects(&scaled(&crossing, scale)),
+                "crossing at {scale}"
+            );
+            assert!(scaled(&crossing, scale).intersects(&a));
+            assert!(!a.intersects(&scaled(&above, scale)), "above at {scale}");
+            assert!(!a.intersects(&scaled(&beside, scale)), "beside at {scale}");
+            assert!(
+                a.intersects(&scaled(&coplanar, scale)),
+                "coplanar at {scale}"
+            );
+            assert!(
+                !a.intersects(&scaled(&coplanar_apart, scale)),
+                "coplanar apart at {scale}"
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_aabb() {
+        let aabb = Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+        let through = triangle((-5.0, -5.0, 0.0), (5.0, -5.0, 0.0), (0.0, 5.0, 0.0));
+        let inside = triangle((0.0, 0.0, 0.0), (0.5, 0.0, 0.0), (0.0, 0.5, 0.0));
+        let outside = triangle((2.0, 2.0, 2.0), (3.0, 2.0, 2.0), (2.0, 3.0, 2.0));
+        // Each vertex's own box range overlaps, but the edge passes the corner.
+        let past_corner = triangle((2.5, 0.0, 0.0), (0.0, 2.5, 0.0), (2.5, 2.5, 0.0));
+        let tilted = triangle((1.5, 0.0, -3.0), (1.5, 0.0, 3.0), (0.5, 3.0, 0.0));
+
+        assert!(through.intersects_aabb(&aabb));
+        assert!(inside.intersects_aabb(&aabb));
+        assert!(!outside.intersects_aabb(&aabb));
+        assert!(!past_corner.intersects_aabb(&aabb));
+        assert!(!tilted.intersects_aabb(&aabb));
+        assert!(triangle((0.5, 0.0, -3.0), (0.5, 0.0, 3.0), (0.5, 3.0, 0.0)).intersects_aabb(&aabb));
+    }
+}
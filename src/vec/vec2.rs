@@ -1,3 +1,4 @@
+use crate::point::Point2D;
 use serde::Deserialize;
 
 #[repr(C)]
@@ -12,10 +13,20 @@ impl Vec2 {
         Self { x, y }
     }
 
+    pub fn idx(&self, idx: usize) -> f32 {
+        [self.x, self.y][idx]
+    }
+
     pub fn zero() -> Self {
         Self::new(0.0, 0.0)
     }
 
+    /// Unit vector at `angle` radians counter-clockwise from +X.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin)
+    }
+
     pub fn negate(&self) -> Self {
         *self * -1.0
     }
@@ -43,6 +54,42 @@ impl Vec2 {
     pub fn dot(&self, rhs: &Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y
     }
+
+    /// The z component of the 3D cross product, positive when `rhs` is
+    /// counter-clockwise from `self`.
+    pub fn cross(&self, rhs: &Self) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Rotated a quarter turn counter-clockwise.
+    pub fn perp(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotated `angle` radians counter-clockwise.
+    pub fn rotate(&self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Angle from +X in radians, in `[-π, π]`.
+    pub fn angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Signed angle from `self` to `rhs` in radians, positive when
+    /// counter-clockwise.
+    pub fn angle_to(&self, rhs: &Self) -> f32 {
+        self.cross(rhs).atan2(self.dot(rhs))
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y))
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y))
+    }
 }
 
 impl From<[f32; 2]> for Vec2 {
@@ -60,6 +107,18 @@ impl From<Vec2> for [f32; 2] {
     }
 }
 
+impl From<Point2D<f32>> for Vec2 {
+    fn from(point: Point2D<f32>) -> Self {
+        Self::new(point.x(), point.y())
+    }
+}
+
+impl From<Vec2> for Point2D<f32> {
+    fn from(vec: Vec2) -> Self {
+        Point2D::new(vec.x, vec.y)
+    }
+}
+
 impl std::ops::Add<Vec2> for Vec2 {
     type Output = Self;
 
@@ -117,3 +176,44 @@ impl std::ops::Div<f32> for Vec2 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn assert_vec(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual - expected).magnitude() < 1e-5,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn angle_round_trip() {
+        for step in -11..=11 {
+            let angle = step as f32 * PI / 12.0;
+            assert!((Vec2::from_angle(angle).angle() - angle).abs() < 1e-5);
+            assert!((Vec2::from_angle(angle).magnitude() - 1.0).abs() < 1e-6);
+            assert!(((Vec2::from_angle(angle) * 3.0).angle() - angle).abs() < 1e-5);
+        }
+        assert_eq!(Vec2::new(-1.0, 0.0).angle(), PI);
+        assert_eq!(Vec2::new(0.0, -2.0).angle(), -FRAC_PI_2);
+        assert_vec(Vec2::from_angle(FRAC_PI_2), Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn rotation_helpers() {
+        let vec = Vec2::new(3.0, 1.0);
+        assert_eq!(vec.perp(), Vec2::new(-1.0, 3.0));
+        assert_vec(vec.rotate(FRAC_PI_2), vec.perp());
+        assert_vec(vec.rotate(PI), vec.negate());
+        assert_vec(vec.rotate(0.7).rotate(-0.7), vec);
+        assert!((vec.rotate(2.0).magnitude() - vec.magnitude()).abs() < 1e-5);
+
+        assert!((vec.angle_to(&vec.rotate(0.5)) - 0.5).abs() < 1e-5);
+        assert!((vec.angle_to(&vec.rotate(-0.5)) + 0.5).abs() < 1e-5);
+        assert!((vec.angle_to(&vec.perp()) - FRAC_PI_2).abs() < 1e-6);
+        assert!((Vec2::new(1.0, 0.0).angle_to(&Vec2::new(0.0, -5.0)) + FRAC_PI_2).abs() < 1e-6);
+    }
+}
@@ -13,6 +13,10 @@ impl Vec3 {
         Self { data: [x, y, z] }
     }
 
+    pub fn idx(&self, idx: usize) -> f32 {
+        self.data[idx]
+    }
+
     pub fn x(&self) -> f32 {
         self.data[0]
     }
@@ -76,6 +80,22 @@ impl Vec3 {
             self.x() * rhs.y() - self.y() * rhs.x(),
         )
     }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.x().min(rhs.x()),
+            self.y().min(rhs.y()),
+            self.z().min(rhs.z()),
+        )
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.x().max(rhs.x()),
+            self.y().max(rhs.y()),
+            self.z().max(rhs.z()),
+        )
+    }
 }
 
 impl From<[f32; 3]> for Vec3 {
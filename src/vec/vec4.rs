@@ -79,6 +79,10 @@ impl Vec4 {
     pub fn dot(&self, rhs: &Self) -> f32 {
         self.x() * rhs.x() + self.y() * rhs.y() + self.z() * rhs.z() + self.w() * rhs.w()
     }
+
+    pub fn xyz(&self) -> Vec3 {
+        Vec3::new(self.x(), self.y(), self.z())
+    }
 }
 
 impl std::ops::Mul<f32> for Vec4 {